@@ -0,0 +1,130 @@
+//! Generates the MIME compressibility database of tide-compress from [jshttp/mime-db][]'s `db.json`.
+//!
+//! The output only depends on the contents of `db.json`, so regenerating from the same input is reproducible.
+//!
+//! [jshttp/mime-db]: https://github.com/jshttp/mime-db
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::Deserialize;
+
+#[path = "../../src/data_kind.rs"]
+mod data_kind;
+
+/// Where `db.json` is fetched from, if no local copy is given.
+pub const DB_URL: &str = "https://raw.githubusercontent.com/jshttp/mime-db/master/db.json";
+
+/// A single entry of `db.json`.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct MimeInfo {
+    /// Where the entry comes from, e.g. `iana`.
+    pub source: Option<String>,
+    /// The default charset.
+    pub charset: Option<String>,
+    /// Whether the type is compressible, if known.
+    pub compressible: Option<bool>,
+    /// File extensions of the type.
+    pub extensions: Option<Vec<String>>,
+}
+
+/// `db.json` could not be read.
+#[derive(Debug)]
+pub enum Error {
+    /// Not valid JSON, or not an object of entries.
+    Json(serde_json::Error),
+    /// An entry does not match the schema of `db.json`.
+    Schema(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Json(e) => write!(f, "invalid db.json: {}", e),
+            Error::Schema(e) => write!(f, "invalid db.json: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Json(e) => Some(e),
+            Error::Schema(_) => None,
+        }
+    }
+}
+
+/// Parses and validates the contents of `db.json`, sorted by MIME type.
+pub fn parse(json: &str) -> Result<BTreeMap<String, MimeInfo>, Error> {
+    let db: BTreeMap<String, MimeInfo> = serde_json::from_str(json).map_err(Error::Json)?;
+    if db.is_empty() {
+        return Err(Error::Schema("no entries".to_owned()));
+    }
+    for (mime, info) in &db {
+        validate(mime, info)?;
+    }
+    Ok(db)
+}
+
+/// Checks the parts of the schema which serde does not.
+fn validate(mime: &str, info: &MimeInfo) -> Result<(), Error> {
+    let valid_part = |part: &str| {
+        !part.is_empty()
+            && part
+                .bytes()
+                .all(|b| b.is_ascii_graphic() && !b.is_ascii_uppercase() && b != b'/')
+    };
+    match mime.split_once('/') {
+        Some((basetype, subtype)) if valid_part(basetype) && valid_part(subtype) => {}
+        _ => {
+            return Err(Error::Schema(format!(
+                "{:?} is not a lowercase `type/subtype`",
+                mime
+            )))
+        }
+    }
+    if let Some(extensions) = &info.extensions {
+        if extensions.iter().any(String::is_empty) {
+            return Err(Error::Schema(format!("{:?} has an empty extension", mime)));
+        }
+    }
+    Ok(())
+}
+
+/// Generates the source of `codegen_database.rs` from the parsed database.
+///
+/// Every entry is kept, with whether it is compressible, not compressible, or unknown,
+/// and the kind of data it holds.
+pub fn generate(db: &BTreeMap<String, MimeInfo>) -> String {
+    let mut builder = phf_codegen::Map::new();
+
+    for (key, info) in db {
+        let compressibility = match info.compressible {
+            Some(true) => "Compressible",
+            Some(false) => "Incompressible",
+            None => "Unknown",
+        };
+        builder.entry(
+            key.as_str(),
+            // The variant names match those imported by the generated source.
+            &format!(
+                "MimeEntry::new({}, {:?})",
+                compressibility,
+                data_kind::data_kind(key)
+            ),
+        );
+    }
+
+    format!(
+        "#![cfg_attr(rustfmt, rustfmt::skip)]\n\
+         \n\
+         use crate::mime_db::Compressibility::{{Compressible, Incompressible, Unknown}};\n\
+         use crate::mime_db::DataKind::{{Font, Generic, Text}};\n\
+         use crate::mime_db::MimeEntry;\n\
+         \n\
+         pub(crate) const MIME_DB: phf::Map<&'static str, MimeEntry> =\n{};\n",
+        builder.build()
+    )
+}
@@ -1,44 +1,44 @@
-use std::collections::HashMap;
 use std::env;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use async_std::fs::File;
-use async_std::io::BufWriter;
-use async_std::io::WriteExt;
-use serde::Deserialize;
-
-#[derive(Debug, Deserialize, Clone)]
-struct MimeInfo {
-    compressible: Option<bool>,
-}
+use async_std::fs;
 
+/// Regenerates `src/codegen_database.rs`.
+///
+/// Reads `db.json` from the path given as the first argument, or else from `generate-database/db.json` if it exists,
+/// and otherwise fetches it from GitHub. Passing `--fetch` fetches it from GitHub even if there is a vendored copy,
+/// to update it.
+///
+/// The `db.json` used is kept as `generate-database/db.json`, alongside the database generated from it.
 #[async_std::main]
 async fn main() -> surf::Result<()> {
-    let path = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap())
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let output = manifest_dir
         .join("..")
         .join("src")
         .join("codegen_database.rs");
-    let mut file = BufWriter::new(File::create(&path).await?);
+    let vendored = manifest_dir.join("db.json");
 
-    let mime_db: HashMap<String, MimeInfo> =
-        surf::get("https://raw.githubusercontent.com/jshttp/mime-db/master/db.json")
-            .recv_json()
-            .await?;
+    let json = match env::args_os().nth(1) {
+        Some(flag) if flag == "--fetch" => fetch().await?,
+        Some(input) => read(Path::new(&input)).await?,
+        None if vendored.exists() => read(&vendored).await?,
+        None => fetch().await?,
+    };
 
-    let mut builder = phf_codegen::Set::new();
+    let db = generate_database::parse(&json)?;
+    fs::write(&output, generate_database::generate(&db)).await?;
+    fs::write(&vendored, &json).await?;
 
-    for (key, info) in mime_db {
-        if matches!(info.compressible, Some(yes) if yes) {
-            builder.entry(key);
-        }
-    }
+    Ok(())
+}
 
-    writeln!(
-        &mut file,
-        "pub(crate) const MIME_DB: phf::Set<&'static str> =\n{};\n",
-        builder.build()
-    )
-    .await?;
+async fn fetch() -> surf::Result<String> {
+    eprintln!("Fetching {}", generate_database::DB_URL);
+    surf::get(generate_database::DB_URL).recv_string().await
+}
 
-    Ok(())
+async fn read(path: &Path) -> surf::Result<String> {
+    eprintln!("Reading {}", path.display());
+    Ok(fs::read_to_string(path).await?)
 }
@@ -0,0 +1,76 @@
+use std::fs;
+use std::path::Path;
+
+/// A small `db.json`, for checking the generator itself.
+const FIXTURE: &str = include_str!("fixtures/db.json");
+
+#[test]
+fn regenerates_committed_database() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // The `db.json` which the committed database was generated from, kept by `cargo run -p generate-database`.
+    let vendored = fs::read_to_string(manifest_dir.join("db.json"))
+        .expect("generate-database/db.json is vendored");
+    let committed = fs::read_to_string(
+        manifest_dir
+            .join("..")
+            .join("src")
+            .join("codegen_database.rs"),
+    )
+    .unwrap();
+
+    let db = generate_database::parse(&vendored).unwrap();
+    let generated = generate_database::generate(&db);
+    assert!(
+        generated == committed,
+        "src/codegen_database.rs is out of date with generate-database/db.json"
+    );
+}
+
+#[test]
+fn deterministic() {
+    let db = generate_database::parse(FIXTURE).unwrap();
+    let generated = generate_database::generate(&db);
+    let db = generate_database::parse(FIXTURE).unwrap();
+    assert_eq!(generate_database::generate(&db), generated);
+}
+
+#[test]
+fn tri_state_entries() {
+    let db = generate_database::parse(FIXTURE).unwrap();
+    let generated = generate_database::generate(&db);
+
+    for entry in [
+        r#"("text/html", MimeEntry::new(Compressible, Text))"#,
+        r#"("font/ttf", MimeEntry::new(Compressible, Font))"#,
+        r#"("image/png", MimeEntry::new(Incompressible, Generic))"#,
+        r#"("application/x-abiword", MimeEntry::new(Unknown, Generic))"#,
+    ] {
+        assert!(generated.contains(entry), "missing {}", entry);
+    }
+}
+
+#[test]
+fn schema_validation() {
+    for invalid in [
+        r#"[]"#,
+        r#"{}"#,
+        r#"{"text/html": {"compressible": "yes"}}"#,
+        r#"{"text/html": {"compressable": true}}"#,
+        r#"{"text/html": {"extensions": [""]}}"#,
+        r#"{"text": {}}"#,
+        r#"{"Text/HTML": {}}"#,
+        r#"{"text/html; charset=utf-8": {}}"#,
+    ] {
+        assert!(
+            generate_database::parse(invalid).is_err(),
+            "{} should be invalid",
+            invalid
+        );
+    }
+
+    let db = generate_database::parse(
+        r#"{"text/html": {"source": "iana", "charset": "UTF-8", "compressible": true, "extensions": ["html"]}}"#,
+    )
+    .unwrap();
+    assert_eq!(db["text/html"].compressible, Some(true));
+}
@@ -0,0 +1,201 @@
+use std::io::{self, Write};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use async_compression::Level;
+use brotli::enc::backward_references::BrotliEncoderMode;
+use brotli::enc::writer::CompressorWriter;
+use brotli::enc::BrotliEncoderParams;
+use futures_lite::io::AsyncWrite;
+use tide::http::Mime;
+
+use crate::data_kind::{data_kind, DataKind};
+use crate::flush::Compressor;
+
+/// The smallest and largest Brotli window size, as the base 2 logarithm of the size in bytes.
+const WINDOW_RANGE: (u32, u32) = (10, 24);
+
+/// Brotli-specific encoder parameters, beyond the quality.
+///
+/// When left at their defaults, the Brotli encoder is used as is.
+///
+/// ## Example
+/// ```rust
+/// # async_std::task::block_on(async {
+/// use tide_compress::{BrotliMode, BrotliParams};
+///
+/// let mut app = tide::new();
+///
+/// app.with(
+///     tide_compress::CompressMiddleware::builder()
+///         .brotli_params(
+///             BrotliParams::new()
+///                 .window(24)
+///                 .mode(BrotliMode::FromContentType)
+///                 .size_hint(true),
+///         )
+///         .build(),
+/// );
+/// # })
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BrotliParams {
+    /// The sliding window size (LGWIN), as the base 2 logarithm of the size in bytes, from `10` to `24`. Default: `None`, the encoder's own (`22`).
+    ///
+    /// Larger windows find matches further back, which helps large payloads, at the cost of memory on both ends.
+    pub window: Option<u32>,
+    /// The kind of data the encoder is tuned for. Default: `BrotliMode::Generic`.
+    pub mode: BrotliMode,
+    /// Pass the body length, when known, to the encoder as a size hint. Default: `false`.
+    pub size_hint: bool,
+}
+
+/// The kind of data the Brotli encoder is tuned for, see [`BrotliParams`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BrotliMode {
+    /// No assumptions about the data. The default.
+    #[default]
+    Generic,
+    /// UTF-8 text.
+    Text,
+    /// WOFF 2.0 fonts.
+    Font,
+    /// Chosen from the response `Content-Type`: `Font` for fonts, `Text` for text, JSON, JavaScript & XML, and otherwise `Generic`.
+    ///
+    /// With the `db-check` feature, types listed in the MIME database use its kind of data.
+    FromContentType,
+}
+
+impl BrotliParams {
+    /// Creates parameters which leave the Brotli encoder as is.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the sliding window size (LGWIN), as the base 2 logarithm of the size in bytes, clamped to `10` to `24`.
+    pub fn window(mut self, window: u32) -> Self {
+        self.window = Some(window.clamp(WINDOW_RANGE.0, WINDOW_RANGE.1));
+        self
+    }
+
+    /// Sets the kind of data the encoder is tuned for.
+    pub fn mode(mut self, mode: BrotliMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether the body length, when known, is passed to the encoder as a size hint.
+    pub fn size_hint(mut self, size_hint: bool) -> Self {
+        self.size_hint = size_hint;
+        self
+    }
+
+    /// Whether the parameters leave the Brotli encoder as is.
+    pub(crate) fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Resolves the parameters for a single response.
+    pub(crate) fn encoder_params(
+        &self,
+        quality: Level,
+        content_type: Option<&Mime>,
+        body_len: Option<usize>,
+    ) -> BrotliEncoderParams {
+        let mut params = BrotliEncoderParams::default();
+        // Mirrors async-compression's own conversion from `Level`.
+        match quality {
+            Level::Fastest => params.quality = 0,
+            Level::Best => params.quality = 11,
+            Level::Precise(quality) => params.quality = quality.min(11) as i32,
+            _ => {}
+        }
+        if let Some(window) = self.window {
+            params.lgwin = window.clamp(WINDOW_RANGE.0, WINDOW_RANGE.1) as i32;
+        }
+        params.mode = match self.mode {
+            BrotliMode::Generic => BrotliEncoderMode::BROTLI_MODE_GENERIC,
+            BrotliMode::Text => BrotliEncoderMode::BROTLI_MODE_TEXT,
+            BrotliMode::Font => BrotliEncoderMode::BROTLI_MODE_FONT,
+            BrotliMode::FromContentType => mode_of(content_type),
+        };
+        if let (true, Some(body_len)) = (self.size_hint, body_len) {
+            params.size_hint = body_len;
+        }
+        params
+    }
+}
+
+/// Picks the encoder mode from the `Content-Type`.
+fn mode_of(content_type: Option<&Mime>) -> BrotliEncoderMode {
+    let essence = match content_type {
+        Some(mime) => mime.essence(),
+        None => return BrotliEncoderMode::BROTLI_MODE_GENERIC,
+    };
+    // The MIME database knows the kind of data of listed types, and others are told from the name alike.
+    #[cfg(feature = "db-check")]
+    let kind =
+        crate::mime_db::lookup(essence).map_or_else(|| data_kind(essence), |entry| entry.kind);
+    #[cfg(not(feature = "db-check"))]
+    let kind = data_kind(essence);
+    match kind {
+        DataKind::Generic => BrotliEncoderMode::BROTLI_MODE_GENERIC,
+        DataKind::Text => BrotliEncoderMode::BROTLI_MODE_TEXT,
+        DataKind::Font => BrotliEncoderMode::BROTLI_MODE_FONT,
+    }
+}
+
+/// A Brotli compressor with custom parameters, writing into an in-memory buffer.
+pub(crate) struct BrotliCompressor {
+    /// The encoder, until it is finished.
+    writer: Option<CompressorWriter<Vec<u8>>>,
+    /// The output of the finished encoder.
+    finished: Vec<u8>,
+}
+
+impl BrotliCompressor {
+    pub(crate) fn new(params: &BrotliEncoderParams) -> Self {
+        Self {
+            writer: Some(CompressorWriter::with_params(vec![], 0, params)),
+            finished: vec![],
+        }
+    }
+}
+
+impl Compressor for BrotliCompressor {
+    fn output(&mut self) -> &mut Vec<u8> {
+        match &mut self.writer {
+            Some(writer) => writer.get_mut(),
+            None => &mut self.finished,
+        }
+    }
+}
+
+// Writing into memory never blocks, so these are always ready.
+impl AsyncWrite for BrotliCompressor {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Poll::Ready(match &mut self.writer {
+            Some(writer) => writer.write(buf),
+            None => Err(io::ErrorKind::BrokenPipe.into()),
+        })
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(match &mut self.writer {
+            Some(writer) => writer.flush(),
+            None => Ok(()),
+        })
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        if let Some(writer) = self.writer.take() {
+            // Finishes the stream, keeping any output which was not read yet.
+            self.finished = writer.into_inner();
+        }
+        Poll::Ready(Ok(()))
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use tide::http::content::Encoding;
+
+/// A bounded, least-recently-used cache of compressed response bodies.
+///
+/// Entries are keyed by request path & query, the response's strong `ETag`, and the chosen encoding.
+/// When a response for a path & query arrives with a different `ETag`, the entries for the previous `ETag` are dropped.
+///
+/// Only bodies compressed with the usual settings are cached: not those with a level from a `CompressionOverride`,
+/// nor any while a `LevelPolicy` is set, as the level it picks may change from one response to the next.
+///
+/// Clones share the same underlying cache, so a clone can be kept around to inspect the hit & miss counters.
+///
+/// ## Example
+/// ```rust
+/// # async_std::task::block_on(async {
+/// let mut app = tide::new();
+///
+/// let cache = tide_compress::CompressionCache::new(16 * 1024 * 1024);
+///
+/// app.with(
+///     tide_compress::CompressMiddleware::builder()
+///         .cache(Some(cache.clone()))
+///         .build(),
+/// );
+///
+/// assert_eq!(cache.hits(), 0);
+/// # })
+/// ```
+#[derive(Clone, Debug)]
+pub struct CompressionCache {
+    inner: Arc<CacheInner>,
+}
+
+#[derive(Debug)]
+struct CacheInner {
+    max_size: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    entries: HashMap<CacheKey, CacheEntry>,
+    /// Recency order, oldest first.
+    recency: BTreeMap<u64, CacheKey>,
+    /// The keys of each path & query, to find stale entries.
+    resources: HashMap<String, HashSet<CacheKey>>,
+    size: usize,
+    tick: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct CacheKey {
+    /// The request path & query.
+    resource: String,
+    etag: String,
+    // `Encoding` does not implement `Hash`.
+    encoding: String,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    body: Arc<[u8]>,
+    tick: u64,
+}
+
+impl CompressionCache {
+    /// Creates a new cache holding at most `max_size` bytes of compressed bodies.
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            inner: Arc::new(CacheInner {
+                max_size,
+                hits: AtomicU64::new(0),
+                misses: AtomicU64::new(0),
+                state: Mutex::new(CacheState::default()),
+            }),
+        }
+    }
+
+    /// The maximum number of bytes of compressed bodies held.
+    pub fn max_size(&self) -> usize {
+        self.inner.max_size
+    }
+
+    /// The number of bytes of compressed bodies currently held.
+    pub fn size(&self) -> usize {
+        self.state().size
+    }
+
+    /// The number of compressed bodies currently held.
+    pub fn len(&self) -> usize {
+        self.state().entries.len()
+    }
+
+    /// Whether the cache is currently empty.
+    pub fn is_empty(&self) -> bool {
+        self.state().entries.is_empty()
+    }
+
+    /// The number of responses served from the cache.
+    pub fn hits(&self) -> u64 {
+        self.inner.hits.load(Ordering::Relaxed)
+    }
+
+    /// The number of cacheable responses which had to be compressed.
+    pub fn misses(&self) -> u64 {
+        self.inner.misses.load(Ordering::Relaxed)
+    }
+
+    /// Removes all entries. Does not reset the hit & miss counters.
+    pub fn clear(&self) {
+        let mut state = self.state();
+        state.entries.clear();
+        state.recency.clear();
+        state.resources.clear();
+        state.size = 0;
+    }
+
+    /// Looks up a compressed body, counting a hit or a miss.
+    pub(crate) fn get(&self, resource: &str, etag: &str, encoding: Encoding) -> Option<Arc<[u8]>> {
+        let key = CacheKey::new(resource, etag, encoding);
+
+        let mut state = self.state();
+        state.tick += 1;
+        let tick = state.tick;
+
+        let found = match state.entries.get_mut(&key) {
+            Some(entry) => {
+                let previous = entry.tick;
+                entry.tick = tick;
+                Some((previous, Arc::clone(&entry.body)))
+            }
+            None => None,
+        };
+
+        match found {
+            Some((previous, body)) => {
+                state.recency.remove(&previous);
+                state.recency.insert(tick, key);
+                self.inner.hits.fetch_add(1, Ordering::Relaxed);
+                Some(body)
+            }
+            None => {
+                self.inner.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Stores a compressed body, evicting the least recently used entries as necessary.
+    ///
+    /// Entries for the same path & query with a different `ETag` are stale and are removed.
+    pub(crate) fn insert(&self, resource: &str, etag: &str, encoding: Encoding, body: Arc<[u8]>) {
+        if body.len() > self.inner.max_size {
+            return;
+        }
+
+        let key = CacheKey::new(resource, etag, encoding);
+
+        let mut state = self.state();
+        let stale: Vec<CacheKey> = match state.resources.get(&key.resource) {
+            Some(keys) => keys
+                .iter()
+                .filter(|existing| existing.etag != key.etag)
+                .cloned()
+                .collect(),
+            None => vec![],
+        };
+        for stale in stale.iter().chain(Some(&key)) {
+            state.remove(stale);
+        }
+
+        while state.size + body.len() > self.inner.max_size {
+            let oldest = match state.recency.values().next() {
+                Some(oldest) => oldest.clone(),
+                None => break,
+            };
+            state.remove(&oldest);
+        }
+
+        state.tick += 1;
+        let tick = state.tick;
+        state.size += body.len();
+        state.recency.insert(tick, key.clone());
+        state
+            .resources
+            .entry(key.resource.clone())
+            .or_default()
+            .insert(key.clone());
+        state.entries.insert(key, CacheEntry { body, tick });
+    }
+
+    fn state(&self) -> MutexGuard<'_, CacheState> {
+        // The state is always left consistent, even if a panic occurred elsewhere while holding the lock.
+        self.inner
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl CacheState {
+    fn remove(&mut self, key: &CacheKey) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.tick);
+            self.size -= entry.body.len();
+            if let Some(keys) = self.resources.get_mut(&key.resource) {
+                keys.remove(key);
+                if keys.is_empty() {
+                    self.resources.remove(&key.resource);
+                }
+            }
+        }
+    }
+}
+
+impl CacheKey {
+    fn new(resource: &str, etag: &str, encoding: Encoding) -> Self {
+        Self {
+            resource: resource.to_owned(),
+            etag: etag.to_owned(),
+            encoding: encoding.to_string(),
+        }
+    }
+}
@@ -1,772 +1,793 @@
-pub(crate) const MIME_DB: phf::Set<&'static str> =
-::phf::Set { map: ::phf::Map {
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+use crate::mime_db::Compressibility::{Compressible, Incompressible, Unknown};
+use crate::mime_db::DataKind::{Font, Generic, Text};
+use crate::mime_db::MimeEntry;
+
+pub(crate) const MIME_DB: phf::Map<&'static str, MimeEntry> =
+::phf::Map {
     key: 12913932095322966823,
     disps: &[
-        (0, 92),
-        (0, 262),
-        (0, 6),
-        (0, 224),
-        (0, 165),
-        (0, 8),
-        (0, 13),
-        (0, 57),
-        (0, 1),
-        (0, 10),
-        (0, 30),
-        (0, 10),
-        (0, 1),
+        (0, 14),
+        (0, 590),
+        (0, 153),
+        (0, 0),
+        (0, 183),
+        (0, 243),
         (0, 1),
+        (0, 498),
+        (0, 215),
+        (1, 317),
+        (0, 110),
+        (0, 156),
+        (0, 212),
+        (0, 489),
+        (1, 59),
+        (0, 38),
+        (0, 504),
+        (0, 68),
         (0, 1),
+        (0, 24),
+        (0, 326),
+        (0, 46),
         (0, 0),
-        (0, 97),
-        (0, 116),
-        (0, 21),
-        (0, 0),
-        (0, 23),
-        (0, 108),
-        (1, 447),
-        (1, 529),
-        (0, 0),
-        (0, 104),
-        (0, 207),
-        (0, 377),
-        (1, 169),
-        (0, 303),
+        (1, 73),
+        (0, 49),
+        (2, 571),
+        (0, 30),
+        (0, 1),
+        (0, 50),
+        (2, 41),
+        (1, 0),
         (0, 112),
-        (0, 128),
-        (0, 5),
-        (0, 23),
-        (0, 62),
+        (0, 64),
+        (0, 94),
+        (0, 60),
+        (0, 38),
         (0, 0),
-        (0, 143),
-        (0, 2),
-        (0, 113),
-        (0, 71),
-        (0, 0),
-        (1, 193),
-        (0, 3),
-        (1, 607),
+        (0, 17),
+        (1, 133),
+        (1, 66),
+        (0, 31),
+        (0, 385),
+        (0, 8),
+        (0, 144),
         (0, 1),
+        (1, 4),
+        (0, 228),
+        (0, 177),
+        (0, 554),
         (0, 0),
-        (1, 590),
-        (0, 20),
-        (0, 102),
-        (0, 204),
-        (0, 19),
+        (3, 158),
+        (0, 454),
+        (0, 237),
+        (0, 28),
+        (0, 381),
         (0, 2),
-        (0, 447),
-        (1, 14),
-        (1, 613),
-        (1, 141),
-        (1, 227),
-        (0, 23),
-        (0, 264),
-        (0, 0),
-        (0, 62),
-        (2, 155),
-        (1, 409),
-        (1, 260),
-        (0, 19),
-        (3, 494),
-        (3, 477),
-        (0, 483),
-        (3, 177),
-        (10, 481),
-        (0, 366),
-        (0, 0),
-        (2, 632),
+        (0, 279),
         (0, 21),
         (0, 2),
-        (0, 99),
-        (0, 12),
-        (2, 242),
-        (0, 293),
-        (0, 363),
-        (0, 125),
-        (0, 127),
-        (0, 13),
-        (0, 2),
-        (4, 578),
-        (0, 71),
-        (1, 559),
-        (3, 586),
-        (0, 11),
+        (0, 40),
+        (0, 21),
+        (0, 29),
+        (0, 67),
+        (0, 8),
+        (1, 291),
         (0, 39),
+        (0, 25),
+        (1, 210),
+        (0, 30),
         (0, 0),
-        (0, 143),
-        (0, 0),
-        (0, 163),
-        (0, 135),
-        (0, 555),
-        (0, 238),
-        (3, 227),
-        (1, 161),
-        (1, 12),
-        (16, 415),
-        (0, 24),
-        (0, 5),
-        (1, 511),
-        (1, 315),
-        (0, 211),
-        (13, 475),
-        (0, 9),
-        (7, 145),
-        (1, 530),
-        (0, 20),
-        (10, 57),
-        (0, 44),
+        (0, 347),
         (0, 0),
+        (0, 1),
+        (0, 2),
+        (0, 121),
+        (0, 274),
         (0, 8),
-        (0, 14),
-        (0, 300),
-        (22, 413),
-        (1, 336),
-        (6, 28),
-        (26, 589),
-        (8, 556),
-        (1, 44),
-        (0, 73),
-        (2, 415),
-        (3, 383),
+        (0, 15),
+        (2, 54),
+        (1, 423),
+        (1, 387),
+        (5, 82),
+        (2, 399),
+        (0, 200),
+        (5, 198),
+        (3, 597),
+        (0, 251),
+        (0, 0),
+        (1, 71),
+        (0, 6),
+        (0, 2),
+        (0, 78),
+        (6, 422),
+        (4, 561),
         (0, 1),
-        (1, 132),
+        (0, 17),
+        (0, 159),
+        (0, 3),
+        (0, 171),
+        (0, 37),
+        (0, 327),
+        (0, 154),
+        (0, 27),
+        (1, 423),
+        (0, 452),
+        (8, 101),
+        (0, 23),
+        (0, 21),
+        (14, 549),
+        (0, 254),
+        (0, 554),
+        (0, 107),
+        (19, 171),
+        (0, 632),
+        (0, 71),
+        (0, 57),
+        (30, 412),
+        (0, 279),
+        (0, 170),
+        (18, 641),
+        (0, 83),
+        (0, 282),
+        (3, 445),
+        (2, 4),
+        (0, 0),
+        (6, 633),
+        (0, 2),
+        (13, 456),
+        (0, 148),
+        (0, 3),
     ],
     entries: &[
-        ("application/dssc+xml", ()),
-        ("application/vnd.geocube+xml", ()),
-        ("application/mbms-envelope+xml", ()),
-        ("application/geo+json", ()),
-        ("application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.wordprocessingml.settings+xml", ()),
-        ("application/x-httpd-php", ()),
-        ("application/vnd.openxmlformats-officedocument.drawingml.chartshapes+xml", ()),
-        ("application/vnd.wv.csp+xml", ()),
-        ("application/jsonml+json", ()),
-        ("application/vnd.syncml+xml", ()),
-        ("application/vnd.oma.pal+xml", ()),
-        ("application/xhtml-voice+xml", ()),
-        ("application/vnd.coreos.ignition+json", ()),
-        ("application/mbms-register+xml", ()),
-        ("application/gpx+xml", ()),
-        ("application/vnd.etsi.simservs+xml", ()),
-        ("application/elm+json", ()),
-        ("application/route-s-tsid+xml", ()),
-        ("application/patch-ops-error+xml", ()),
-        ("application/xhtml+xml", ()),
-        ("application/vnd.tableschema+json", ()),
-        ("application/geoxacml+xml", ()),
-        ("application/davmount+xml", ()),
-        ("application/emergencycalldata.deviceinfo+xml", ()),
-        ("application/xml", ()),
-        ("application/vnd.openxmlformats-officedocument.themeoverride+xml", ()),
-        ("application/vnd.etsi.iptvservice+xml", ()),
-        ("application/vnd.oipf.dae.svg+xml", ()),
-        ("application/vnd.apple.installer+xml", ()),
-        ("application/vnd.micro+json", ()),
-        ("application/vnd.etsi.tsl+xml", ()),
-        ("application/samlassertion+xml", ()),
-        ("application/vnd.oma.bcast.sprov+xml", ()),
-        ("application/yang-patch+json", ()),
-        ("application/vnd.xacml+json", ()),
-        ("model/step+xml", ()),
-        ("application/sparql-results+xml", ()),
-        ("application/jscalendar+json", ()),
-        ("application/sensml+json", ()),
-        ("application/reputon+json", ()),
-        ("application/srgs+xml", ()),
-        ("image/vnd.microsoft.icon", ()),
-        ("application/mads+xml", ()),
-        ("application/vnd.nokia.iptv.config+xml", ()),
-        ("application/alto-networkmap+json", ()),
-        ("application/conference-info+xml", ()),
-        ("application/vnd.las.las+xml", ()),
-        ("application/vnd.3gpp.ussd+xml", ()),
-        ("application/vnd.pwg-xhtml-print+xml", ()),
-        ("application/mbms-schedule+xml", ()),
-        ("application/vnd.3gpp.mcvideo-service-config+xml", ()),
-        ("application/vnd.shopkick+json", ()),
-        ("application/emotionml+xml", ()),
-        ("application/vnd.shootproof+json", ()),
-        ("application/mbms-protection-description+xml", ()),
-        ("application/vnd.nokia.pcd+xml", ()),
-        ("application/vnd.tmd.mediaflex.api+xml", ()),
-        ("application/vnd.ims.lti.v2.toolproxy.id+json", ()),
-        ("application/yang-patch+xml", ()),
-        ("application/vnd.dvb.notif-aggregate-root+xml", ()),
-        ("application/mathml+xml", ()),
-        ("application/xenc+xml", ()),
-        ("application/vnd.oipf.spdiscovery+xml", ()),
-        ("application/vnd.openxmlformats-package.relationships+xml", ()),
-        ("application/vnd.ctct.ws+xml", ()),
-        ("application/alto-cdnifilter+json", ()),
-        ("application/vnd.vel+json", ()),
-        ("application/vnd.sycle+xml", ()),
-        ("application/vnd.eu.kasparian.car+json", ()),
-        ("application/inkml+xml", ()),
-        ("application/rdap+json", ()),
-        ("application/simple-filter+xml", ()),
-        ("application/vnd.3gpp.mcdata-regroup+xml", ()),
-        ("application/yang-data+xml", ()),
-        ("x-shader/x-fragment", ()),
-        ("application/vnd.dataresource+json", ()),
-        ("application/vnd.las.las+json", ()),
-        ("application/vnd.ms-opentype", ()),
-        ("application/yin+xml", ()),
-        ("application/atsc-rdt+json", ()),
-        ("application/vnd.oma.cab-user-prefs+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml", ()),
-        ("application/cstadata+xml", ()),
-        ("application/calendar+json", ()),
-        ("text/csv", ()),
-        ("application/vnd.3gpp.mcptt-service-config+xml", ()),
-        ("application/xcap-att+xml", ()),
-        ("application/x-web-app-manifest+json", ()),
-        ("application/aif+json", ()),
-        ("text/x-suse-ymp", ()),
-        ("application/hl7v2+xml", ()),
-        ("application/vnd.amundsen.maze+xml", ()),
-        ("application/dart", ()),
-        ("application/rdf+xml", ()),
-        ("application/vnd.mozilla.xul+xml", ()),
-        ("application/vnd.3gpp.mcdata-affiliation-command+xml", ()),
-        ("application/vnd.seis+json", ()),
-        ("application/vnd.syft+json", ()),
-        ("application/vnd.oma.cab-subs-invite+xml", ()),
-        ("application/vnd.3gpp.mcptt-mbms-usage-info+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.handoutmaster+xml", ()),
-        ("application/vnd.syncml.dm+xml", ()),
-        ("application/ssml+xml", ()),
-        ("application/vnd.oma.bcast.notification+xml", ()),
-        ("application/vnd.3gpp.mcptt-info+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.volatiledependencies+xml", ()),
-        ("application/vnd.cyclonedx+json", ()),
-        ("application/vnd.ms-playready.initiator+xml", ()),
-        ("application/x-dtbncx+xml", ()),
-        ("application/vnd.iptc.g2.planningitem+xml", ()),
-        ("application/cea-2018+xml", ()),
-        ("application/vnd.futoin+json", ()),
-        ("application/vnd.openxmlformats-officedocument.wordprocessingml.template.main+xml", ()),
-        ("application/vnd.oma.poc.final-report+xml", ()),
-        ("application/emergencycalldata.providerinfo+xml", ()),
-        ("application/json-patch+json", ()),
-        ("text/uri-list", ()),
-        ("application/cda+xml", ()),
-        ("application/fhir+json", ()),
-        ("application/vnd.radisys.msml-audit-stream+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.sharedstrings+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.pivotcacherecords+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.calcchain+xml", ()),
-        ("application/mods+xml", ()),
-        ("application/vnd.gov.sk.e-form+xml", ()),
-        ("application/vnd.omads-email+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.template.main+xml", ()),
-        ("application/vnd.3gpp-prose+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.pivottable+xml", ()),
-        ("application/vnd.sun.wadl+xml", ()),
-        ("application/vnd.criticaltools.wbs+xml", ()),
-        ("application/resource-lists-diff+xml", ()),
-        ("application/atsc-rsat+xml", ()),
-        ("application/x-tar", ()),
-        ("application/vnd.3gpp.mcptt-affiliation-command+xml", ()),
-        ("application/linkset+json", ()),
-        ("application/vnd.gnu.taler.exchange+json", ()),
-        ("application/vnd.apothekende.reservation+json", ()),
-        ("text/calender", ()),
-        ("application/vnd.openxmlformats-officedocument.drawingml.chart+xml", ()),
-        ("application/soap+xml", ()),
-        ("application/vnd.etsi.sci+xml", ()),
-        ("application/atomcat+xml", ()),
-        ("application/samlmetadata+xml", ()),
-        ("application/vnd.datapackage+json", ()),
-        ("application/vnd.oma.poc.optimized-progress-report+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.custom-properties+xml", ()),
-        ("application/marcxml+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.tablesinglecells+xml", ()),
-        ("application/cnrp+xml", ()),
-        ("application/vnd.avistar+xml", ()),
-        ("text/x-processing", ()),
-        ("application/alto-error+json", ()),
-        ("model/gltf+json", ()),
-        ("application/vnd.infotech.project+xml", ()),
-        ("application/jrd+json", ()),
-        ("application/vnd.route66.link66+xml", ()),
-        ("application/spdx+json", ()),
-        ("application/urc-grpsheet+xml", ()),
-        ("application/vnd.3gpp.mcvideo-mbms-usage-info+xml", ()),
-        ("application/ibe-key-request+xml", ()),
-        ("application/vnd.3gpp.mcvideo-user-profile+xml", ()),
-        ("application/msc-ivr+xml", ()),
-        ("application/dskpp+xml", ()),
-        ("text/jsx", ()),
-        ("model/vnd.moml+xml", ()),
-        ("application/activity+json", ()),
-        ("application/vnd.etsi.iptvueprofile+xml", ()),
-        ("application/beep+xml", ()),
-        ("application/auth-policy+xml", ()),
-        ("application/vnd.api+json", ()),
-        ("application/jf2feed+json", ()),
-        ("application/td+json", ()),
-        ("application/vnd.byu.uapi+json", ()),
-        ("application/mbms-user-service-description+xml", ()),
-        ("application/vnd.eszigno3+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.dialogsheet+xml", ()),
-        ("application/emergencycalldata.comment+xml", ()),
-        ("application/webpush-options+json", ()),
-        ("application/vnd.openblox.game+xml", ()),
-        ("text/mdx", ()),
-        ("application/scaip+xml", ()),
-        ("application/vnd.openstreetmap.data+xml", ()),
-        ("application/vnd.restful+json", ()),
-        ("application/alto-networkmapfilter+json", ()),
-        ("application/vnd.handheld-entertainment+xml", ()),
-        ("application/mediaservercontrol+xml", ()),
-        ("text/yaml", ()),
-        ("application/vnd.iptc.g2.newsitem+xml", ()),
-        ("application/x-virtualbox-vhd", ()),
-        ("application/dicom+json", ()),
-        ("application/vnd.think-cell.ppttc+json", ()),
-        ("application/vnd.etsi.iptvsad-cod+xml", ()),
-        ("application/senml+xml", ()),
-        ("application/vnd.radisys.msml-audit-conf+xml", ()),
-        ("application/vnd.dvb.notif-ia-registration-response+xml", ()),
-        ("application/xcon-conference-info+xml", ()),
-        ("text/plain", ()),
-        ("application/vnd.radisys.msml-dialog-base+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.drawingml.diagramlayout+xml", ()),
-        ("application/rlmi+xml", ()),
-        ("application/tei+xml", ()),
-        ("application/ppsp-tracker+json", ()),
-        ("application/vnd.pagerduty+json", ()),
-        ("application/vnd.eclipse.ditto+json", ()),
-        ("application/media_control+xml", ()),
-        ("application/senml+json", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.connections+xml", ()),
-        ("application/sarif+json", ()),
-        ("application/tar", ()),
-        ("application/vnd.radisys.msml-conf+xml", ()),
-        ("model/x3d+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.querytable+xml", ()),
-        ("application/vnd.3gpp.mcptt-signed+xml", ()),
-        ("application/vnd.poc.group-advertisement+xml", ()),
-        ("application/ccmp+xml", ()),
-        ("application/vnd.etsi.iptvsad-npvr+xml", ()),
-        ("application/expect-ct-report+json", ()),
-        ("application/vnd.adobe.xdp+xml", ()),
-        ("application/mrb-consumer+xml", ()),
-        ("application/alto-updatestreamcontrol+json", ()),
-        ("application/vnd.cyclonedx+xml", ()),
-        ("application/alto-cdni+json", ()),
-        ("application/jose+json", ()),
-        ("application/vnd.oma.bcast.associated-procedure-parameter+xml", ()),
-        ("application/voucher-cms+json", ()),
-        ("application/vnd.openxmlformats-officedocument.theme+xml", ()),
-        ("text/n3", ()),
-        ("application/3gpphalforms+json", ()),
-        ("application/alto-costmap+json", ()),
-        ("application/vnd.radisys.msml-dialog-fax-sendrecv+xml", ()),
-        ("application/vnd.3gpp.mcptt-user-profile+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.notesslide+xml", ()),
-        ("application/dash-patch+xml", ()),
-        ("application/gml+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.extended-properties+xml", ()),
-        ("text/tab-separated-values", ()),
-        ("application/x-virtualbox-ovf", ()),
-        ("application/emergencycalldata.veds+xml", ()),
-        ("application/mbms-register-response+xml", ()),
-        ("application/reginfo+xml", ()),
-        ("application/xcon-conference-info-diff+xml", ()),
-        ("application/vnd.nokia.n-gage.ac+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.drawingml.diagramcolors+xml", ()),
-        ("text/x-org", ()),
-        ("text/css", ()),
-        ("application/ibe-pkg-reply+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.wordprocessingml.fonttable+xml", ()),
-        ("application/odm+xml", ()),
-        ("application/xcap-diff+xml", ()),
-        ("application/vnd.etsi.iptvcommand+xml", ()),
-        ("text/javascript", ()),
-        ("application/kpml-response+xml", ()),
-        ("application/resource-lists+xml", ()),
-        ("application/vnd.nokia.landmarkcollection+xml", ()),
-        ("application/xop+xml", ()),
-        ("application/vnd.3gpp.sms+xml", ()),
-        ("application/vnd.uoml+xml", ()),
-        ("application/x-xliff+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.wordprocessingml.document.glossary+xml", ()),
-        ("application/mbms-msk+xml", ()),
-        ("application/fdt+xml", ()),
-        ("application/vnd.oipf.contentaccessstreaming+xml", ()),
-        ("application/vnd.eprints.data+xml", ()),
-        ("application/vnd.3gpp.mid-call+xml", ()),
-        ("application/prs.xsf+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml", ()),
-        ("application/metalink4+xml", ()),
-        ("text/html", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.slide+xml", ()),
-        ("application/pidf-diff+xml", ()),
-        ("application/xspf+xml", ()),
-        ("application/vnd.oipf.mippvcontrolmessage+xml", ()),
-        ("application/vnd.oma.bcast.imd+xml", ()),
-        ("application/vnd.oma.scidm.messages+xml", ()),
-        ("application/calendar+xml", ()),
-        ("application/vnd.iptc.g2.newsmessage+xml", ()),
-        ("message/rfc822", ()),
-        ("application/vnd.marlin.drm.license+xml", ()),
-        ("application/x-dtbook+xml", ()),
-        ("application/im-iscomposing+xml", ()),
-        ("application/vnd.3gpp.mcptt-ue-init-config+xml", ()),
-        ("application/vnd.geo+json", ()),
-        ("application/vnd.etsi.overload-control-policy-dataset+xml", ()),
-        ("application/vnd.gentics.grd+json", ()),
-        ("application/vnd.marlin.drm.actiontoken+xml", ()),
-        ("application/sru+xml", ()),
-        ("application/vnd.veritone.aion+json", ()),
-        ("application/json", ()),
-        ("application/x-virtualbox-vdi", ()),
-        ("application/vnd.heroku+json", ()),
-        ("application/poc-settings+xml", ()),
-        ("application/vnd.etsi.cug+xml", ()),
-        ("application/vnd.oftn.l10n+json", ()),
-        ("application/vnd.iptc.g2.conceptitem+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.usernames+xml", ()),
-        ("application/vnd.llamagraphics.life-balance.exchange+xml", ()),
-        ("application/fido.trusted-apps+json", ()),
-        ("application/mud+json", ()),
-        ("application/vnd.avalon+json", ()),
-        ("application/elm+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.slideshow.main+xml", ()),
-        ("application/ld+json", ()),
-        ("application/vnd.3gpp.srvcc-info+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.pivotcachedefinition+xml", ()),
-        ("application/vnd.document+json", ()),
-        ("application/alto-updatestreamparams+json", ()),
-        ("application/vnd.wv.ssp+xml", ()),
-        ("application/mathml-presentation+xml", ()),
-        ("application/alto-propmapparams+json", ()),
-        ("application/vnd.openxmlformats-officedocument.wordprocessingml.footnotes+xml", ()),
-        ("application/alto-endpointcostparams+json", ()),
-        ("application/vnd.hyperdrive+json", ()),
-        ("application/vnd.3gpp.mcdata-ue-config+xml", ()),
-        ("application/vnd.radisys.msml-dialog-fax-detect+xml", ()),
-        ("application/x-ns-proxy-autoconfig", ()),
-        ("application/vnd.radisys.msml-audit-conn+xml", ()),
-        ("application/x-virtualbox-ova", ()),
-        ("application/vnd.oma.bcast.drm-trigger+xml", ()),
-        ("application/vnd.radisys.msml-dialog+xml", ()),
-        ("application/postscript", ()),
-        ("application/vnd.xmi+xml", ()),
-        ("application/dns+json", ()),
-        ("application/stix+json", ()),
-        ("application/vnd.ims.lti.v2.toolsettings.simple+json", ()),
-        ("application/urc-uisocketdesc+xml", ()),
-        ("application/vnd.emclient.accessrequest+xml", ()),
-        ("application/watcherinfo+xml", ()),
-        ("text/xml", ()),
-        ("application/vnd.oma.poc.invocation-descriptor+xml", ()),
-        ("application/wasm", ()),
-        ("application/media-policy-dataset+xml", ()),
-        ("text/vtt", ()),
-        ("application/vnd.dvb.notif-generic+xml", ()),
-        ("application/vnd.3gpp.mcvideo-info+xml", ()),
-        ("application/thraud+xml", ()),
-        ("application/voicexml+xml", ()),
-        ("application/vnd.radisys.msml-dialog-group+xml", ()),
-        ("application/vnd.oma.xcap-directory+xml", ()),
-        ("application/vnd.1000minds.decision-model+xml", ()),
-        ("application/its+xml", ()),
-        ("application/xslt+xml", ()),
-        ("application/route-apd+xml", ()),
-        ("application/x-virtualbox-vmdk", ()),
-        ("application/vnd.nokia.conml+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.wordprocessingml.websettings+xml", ()),
-        ("application/sensml+xml", ()),
-        ("application/lostsync+xml", ()),
-        ("application/alto-endpointpropparams+json", ()),
-        ("application/vnd.dvb.notif-init+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.chartsheet+xml", ()),
-        ("application/raml+yaml", ()),
-        ("application/emergencycalldata.serviceinfo+xml", ()),
-        ("text/rtf", ()),
-        ("application/vnd.dvb.notif-ia-msglist+xml", ()),
-        ("application/nlsml+xml", ()),
-        ("text/less", ()),
-        ("application/manifest+json", ()),
-        ("application/vnd.3gpp-prose-pc3ch+xml", ()),
-        ("application/provenance+xml", ()),
-        ("application/atomdeleted+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml", ()),
-        ("application/vnd.etsi.aoc+xml", ()),
-        ("application/mbms-reception-report+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.drawingml.diagramstyle+xml", ()),
-        ("application/toml", ()),
-        ("application/vnd.bekitzur-stech+json", ()),
-        ("application/senml-etch+json", ()),
-        ("font/ttf", ()),
-        ("application/vnd.3gpp.mcptt-floor-request+xml", ()),
-        ("application/vnd.ms-office.activex+xml", ()),
-        ("application/opc-nodeset+xml", ()),
-        ("application/p2p-overlay+xml", ()),
-        ("application/vnd.hc+json", ()),
-        ("application/vnd.radisys.msml-audit-dialog+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.drawingml.diagramdata+xml", ()),
-        ("application/ecmascript", ()),
-        ("application/emergencycalldata.cap+xml", ()),
-        ("application/metalink+xml", ()),
-        ("application/3gpp-ims+xml", ()),
-        ("application/lgr+xml", ()),
-        ("text/cmd", ()),
-        ("application/framework-attributes+xml", ()),
-        ("application/rtf", ()),
-        ("application/vnd.syncml.dmtnds+xml", ()),
-        ("application/vcard+xml", ()),
-        ("application/vnd.iptc.g2.knowledgeitem+xml", ()),
-        ("application/omdoc+xml", ()),
-        ("application/vnd.gov.sk.xmldatacontainer+xml", ()),
-        ("application/vnd.etsi.iptvsad-bc+xml", ()),
-        ("application/pidf+xml", ()),
-        ("application/mmt-usd+xml", ()),
-        ("application/vnd.chemdraw+xml", ()),
-        ("application/vnd.oma.poc.detailed-progress-report+xml", ()),
-        ("application/load-control+xml", ()),
-        ("application/urc-targetdesc+xml", ()),
-        ("application/msc-mixer+xml", ()),
-        ("application/vnd.ms-printing.printticket+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.wordprocessingml.footer+xml", ()),
-        ("text/richtext", ()),
-        ("application/vnd.dvb.notif-container+xml", ()),
-        ("application/atsc-held+xml", ()),
-        ("application/vnd.nacamar.ybrid+json", ()),
-        ("application/vnd.openxmlformats-officedocument.customxmlproperties+xml", ()),
-        ("application/vnd.openxmlformats-package.digital-signature-xmlsignature+xml", ()),
-        ("application/cwl+json", ()),
-        ("application/vnd.oma.cab-pcc+xml", ()),
-        ("application/vnd.biopax.rdf+xml", ()),
-        ("application/rss+xml", ()),
-        ("x-shader/x-vertex", ()),
-        ("application/vnd.artisan+json", ()),
-        ("application/vnd.ms-fontobject", ()),
-        ("application/vnd.3gpp.state-and-event-info+xml", ()),
-        ("application/tlsrpt+json", ()),
-        ("application/ttml+xml", ()),
-        ("application/wsdl+xml", ()),
-        ("application/vnd.etsi.iptvdiscovery+xml", ()),
-        ("application/ace+json", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.slideupdateinfo+xml", ()),
-        ("application/vnd.siren+json", ()),
-        ("application/alto-costmapfilter+json", ()),
-        ("application/clue+xml", ()),
-        ("message/imdn+xml", ()),
-        ("application/vnd.openxmlformats-package.core-properties+xml", ()),
-        ("application/vnd.iptc.g2.catalogitem+xml", ()),
-        ("application/vnd.gnu.taler.merchant+json", ()),
-        ("application/vnd.oma.lwm2m+json", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.sheetmetadata+xml", ()),
-        ("image/vnd.ms-dds", ()),
-        ("application/x-virtualbox-vbox", ()),
-        ("application/vnd.3gpp.mcvideo-ue-config+xml", ()),
-        ("application/dialog-info+xml", ()),
-        ("application/vnd.radisys.msml-dialog-transform+xml", ()),
-        ("application/xacml+xml", ()),
-        ("application/x-virtualbox-hdd", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.slidemaster+xml", ()),
-        ("application/vnd.dvb.notif-ia-registration-request+xml", ()),
-        ("application/vnd.omads-folder+xml", ()),
-        ("image/svg+xml", ()),
-        ("application/vnd.etsi.iptvprofile+xml", ()),
-        ("application/3gpdash-qoe-report+xml", ()),
-        ("application/vnd.3gpp.srvcc-ext+xml", ()),
-        ("application/cpl+xml", ()),
-        ("application/emma+xml", ()),
-        ("application/vnd.radisys.moml+xml", ()),
-        ("model/gltf-binary", ()),
-        ("application/alto-endpointcost+json", ()),
-        ("application/vnd.software602.filler.form+xml", ()),
-        ("application/pls+xml", ()),
-        ("application/jwk-set+json", ()),
-        ("application/vnd.solent.sdkm+xml", ()),
-        ("application/vnd.oci.image.manifest.v1+json", ()),
-        ("application/vnd.dart", ()),
-        ("application/cdfx+xml", ()),
-        ("application/rls-services+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.revisionheaders+xml", ()),
-        ("application/vnd.bbf.usp.msg+json", ()),
-        ("application/held+xml", ()),
-        ("application/vnd.oracle.resource+json", ()),
-        ("application/vnd.oma.drm.risd+xml", ()),
-        ("application/vnd.3gpp.mcdata-info+xml", ()),
-        ("model/vnd.collada+xml", ()),
-        ("image/x-ms-bmp", ()),
-        ("application/fhir+xml", ()),
-        ("application/mbms-msk-response+xml", ()),
-        ("application/epp+xml", ()),
-        ("application/vcard+json", ()),
-        ("application/vnd.3gpp.gmop+xml", ()),
-        ("application/xml-patch+xml", ()),
-        ("application/mmt-aei+xml", ()),
-        ("application/vnd.dece.ttml+xml", ()),
-        ("application/emergencycalldata.control+xml", ()),
-        ("application/vnd.oipf.contentaccessdownload+xml", ()),
-        ("application/vnd.cryptii.pipe+json", ()),
-        ("application/vnd.marlin.drm.conftoken+xml", ()),
-        ("application/xproc+xml", ()),
-        ("application/urc-ressheet+xml", ()),
-        ("application/vnd.cyan.dean.root+xml", ()),
-        ("application/shf+xml", ()),
-        ("application/vnd.etsi.mcid+xml", ()),
-        ("application/vnd.dvb.dvbisl+xml", ()),
-        ("application/clue_info+xml", ()),
-        ("application/vnd.recordare.musicxml+xml", ()),
-        ("application/vnd.oipf.ueprofile+xml", ()),
-        ("application/problem+xml", ()),
-        ("application/x-javascript", ()),
-        ("application/vnd.3gpp.mcdata-msgstore-ctrl-request+xml", ()),
-        ("application/vnd.hyper-item+json", ()),
-        ("application/xliff+xml", ()),
-        ("application/vnd.3gpp2.bcmcsinfo+xml", ()),
-        ("application/vnd.oma.poc.groups+xml", ()),
-        ("application/jwk+json", ()),
-        ("application/vnd.oma.bcast.smartcard-trigger+xml", ()),
-        ("application/problem+json", ()),
-        ("application/vnd.oipf.spdlist+xml", ()),
-        ("application/vnd.etsi.iptvsync+xml", ()),
-        ("application/vnd.3gpp.mcdata-user-profile+xml", ()),
-        ("application/vnd.balsamiq.bmml+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.comments+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.revisionlog+xml", ()),
-        ("application/atomsvc+xml", ()),
-        ("application/mbms-deregister+xml", ()),
-        ("application/x-dtbresource+xml", ()),
-        ("application/vnd.fujifilm.fb.jfi+xml", ()),
-        ("image/x-icon", ()),
-        ("application/coap-group+json", ()),
-        ("application/sep+xml", ()),
-        ("application/vnd.mason+json", ()),
-        ("application/xcap-caps+xml", ()),
-        ("application/vnd.radisys.msml+xml", ()),
-        ("application/dash+xml", ()),
-        ("application/vnd.iptc.g2.packageitem+xml", ()),
-        ("image/vnd.adobe.photoshop", ()),
-        ("application/vnd.nearst.inv+json", ()),
-        ("application/wspolicy+xml", ()),
-        ("application/mrb-publish+xml", ()),
-        ("application/sbml+xml", ()),
-        ("application/vnd.oma.bcast.sgdd+xml", ()),
-        ("application/merge-patch+json", ()),
-        ("application/vnd.amadeus+json", ()),
-        ("application/vnd.leap+json", ()),
-        ("application/kpml-request+xml", ()),
-        ("application/vnd.omads-file+xml", ()),
-        ("application/vnd.etsi.pstn+xml", ()),
-        ("application/vnd.oma.dd2+xml", ()),
-        ("text/cache-manifest", ()),
-        ("text/x-gwt-rpc", ()),
-        ("application/vnd.zzazz.deck+xml", ()),
-        ("text/x-jquery-tmpl", ()),
-        ("application/vnd.citationstyles.style+xml", ()),
-        ("text/vcard", ()),
-        ("application/sarif-external-properties+json", ()),
-        ("application/vnd.ims.lti.v2.toolproxy+json", ()),
-        ("application/vnd.radisys.msml-dialog-speech+xml", ()),
-        ("image/bmp", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.notesmaster+xml", ()),
-        ("font/otf", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.tags+xml", ()),
-        ("application/mbms-associated-procedure-description+xml", ()),
-        ("application/vnd.collection.next+json", ()),
-        ("application/pskc+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.template.main+xml", ()),
-        ("application/vnd.3gpp.mcvideo-transmission-request+xml", ()),
-        ("application/vnd.hal+xml", ()),
-        ("application/dicom+xml", ()),
-        ("application/vnd.3gpp.mcptt-location-info+xml", ()),
-        ("application/spirits-event+xml", ()),
-        ("application/ccxml+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.tablestyles+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.table+xml", ()),
-        ("application/vnd.capasystems-pg+json", ()),
-        ("application/mets+xml", ()),
-        ("application/yang-data+json", ()),
-        ("application/vnd.yamaha.openscoreformat.osfpvg+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml", ()),
-        ("application/captive+json", ()),
-        ("application/xv+xml", ()),
-        ("text/markdown", ()),
-        ("application/vnd.ims.lti.v2.toolsettings+json", ()),
-        ("application/vnd.otps.ct-kip+xml", ()),
-        ("application/vnd.ms-printdevicecapabilities+xml", ()),
-        ("application/rsd+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.commentauthors+xml", ()),
-        ("application/3gpphal+json", ()),
-        ("application/vnd.openxmlformats-officedocument.wordprocessingml.comments+xml", ()),
-        ("application/vnd.irepository.package+xml", ()),
-        ("application/vnd.oma.group-usage-list+xml", ()),
-        ("application/docbook+xml", ()),
-        ("application/vnd.ms-printschematicket+xml", ()),
-        ("application/vnd.collection+json", ()),
-        ("application/taxii+json", ()),
-        ("application/vnd.oma.cab-address-book+xml", ()),
-        ("application/swid+xml", ()),
-        ("application/vnd.ims.lti.v2.toolconsumerprofile+json", ()),
-        ("application/xcap-error+xml", ()),
-        ("application/vnd.aplextor.warrp+json", ()),
-        ("application/x-sh", ()),
-        ("application/vnd.ims.lis.v2.result+json", ()),
-        ("application/alto-endpointprop+json", ()),
-        ("application/vnd.liberty-request+xml", ()),
-        ("application/atsc-dwd+xml", ()),
-        ("application/smil+xml", ()),
-        ("application/vnd.3gpp.bsf+xml", ()),
-        ("application/vnd.oma.cab-feature-handler+xml", ()),
-        ("application/vnd.informedcontrol.rms+xml", ()),
-        ("application/vnd.3gpp.mcdata-service-config+xml", ()),
-        ("application/scim+json", ()),
-        ("application/rfc+xml", ()),
-        ("application/vnd.miele+json", ()),
-        ("application/vnd.oipf.userprofile+xml", ()),
-        ("application/mathml-content+xml", ()),
-        ("application/javascript", ()),
-        ("application/oebps-package+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml", ()),
-        ("application/vnd.hyper+json", ()),
-        ("application/vnd.syncml.dmddf+xml", ()),
-        ("application/xcap-ns+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.slidelayout+xml", ()),
-        ("application/vnd.3gpp.mcvideo-location-info+xml", ()),
-        ("application/vnd.radisys.msml-audit+xml", ()),
-        ("application/vnd.nokia.landmark+xml", ()),
-        ("application/atom+xml", ()),
-        ("application/vnd.3gpp.mcptt-ue-config+xml", ()),
-        ("application/vnd.3gpp.mcvideo-affiliation-command+xml", ()),
-        ("text/x-markdown", ()),
-        ("application/csta+xml", ()),
-        ("application/x-www-form-urlencoded", ()),
-        ("application/alto-propmap+json", ()),
-        ("application/vnd.3gpp.access-transfer-events+xml", ()),
-        ("application/xcap-el+xml", ()),
-        ("application/city+json", ()),
-        ("application/csvm+json", ()),
-        ("application/vnd.openxmlformats-officedocument.wordprocessingml.endnotes+xml", ()),
-        ("application/cellml+xml", ()),
-        ("application/lost+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.spreadsheetml.externallink+xml", ()),
-        ("application/vnd.hal+json", ()),
-        ("application/vnd.google-earth.kml+xml", ()),
-        ("application/xaml+xml", ()),
-        ("application/xml-dtd", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.viewprops+xml", ()),
-        ("application/alto-directory+json", ()),
-        ("application/vnd.drive+json", ()),
-        ("application/vnd.dm.delegation+xml", ()),
-        ("application/vnd.oipf.dae.xhtml+xml", ()),
-        ("application/route-usd+xml", ()),
-        ("application/xmpp+xml", ()),
-        ("application/vnd.openxmlformats-officedocument.drawing+xml", ()),
-        ("application/vnd.collection.doc+json", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.presprops+xml", ()),
-        ("application/ssdl+xml", ()),
-        ("application/pvd+json", ()),
-        ("application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml", ()),
-        ("application/emergencycalldata.subscriberinfo+xml", ()),
+        ("application/ssml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.iptvprofile+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml-dialog-fax-detect+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xcap-att+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xml-dtd", MimeEntry::new(Compressible, Generic)),
+        ("application/vnd.3gpp.mcptt-ue-config+xml", MimeEntry::new(Compressible, Text)),
+        ("application/linkset+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.nokia.pcd+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.google-earth.kml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.api+json", MimeEntry::new(Compressible, Text)),
+        ("application/gml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/sbml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.cab-subs-invite+xml", MimeEntry::new(Compressible, Text)),
+        ("application/x-ns-proxy-autoconfig", MimeEntry::new(Compressible, Generic)),
+        ("application/rss+xml", MimeEntry::new(Compressible, Text)),
+        ("application/rls-services+xml", MimeEntry::new(Compressible, Text)),
+        ("text/n3", MimeEntry::new(Compressible, Text)),
+        ("application/x-7z-compressed", MimeEntry::new(Incompressible, Generic)),
+        ("application/vnd.openxmlformats-officedocument.drawing+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.iptvueprofile+xml", MimeEntry::new(Compressible, Text)),
+        ("application/its+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.cyan.dean.root+xml", MimeEntry::new(Compressible, Text)),
+        ("application/atomcat+xml", MimeEntry::new(Compressible, Text)),
+        ("application/simple-filter+xml", MimeEntry::new(Compressible, Text)),
+        ("video/mp4", MimeEntry::new(Incompressible, Generic)),
+        ("application/mbms-register+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.shopkick+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.eprints.data+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.tags+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.group-usage-list+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.biopax.rdf+xml", MimeEntry::new(Compressible, Text)),
+        ("application/dicom+json", MimeEntry::new(Compressible, Text)),
+        ("application/cdfx+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.siren+json", MimeEntry::new(Compressible, Text)),
+        ("application/fido.trusted-apps+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.iptvservice+xml", MimeEntry::new(Compressible, Text)),
+        ("application/tlsrpt+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.mcid+xml", MimeEntry::new(Compressible, Text)),
+        ("text/html", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.datapackage+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-package.digital-signature-xmlsignature+xml", MimeEntry::new(Compressible, Text)),
+        ("image/vnd.microsoft.icon", MimeEntry::new(Compressible, Generic)),
+        ("application/vnd.openxmlformats-officedocument.extended-properties+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.handheld-entertainment+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.omads-email+xml", MimeEntry::new(Compressible, Text)),
+        ("application/spdx+json", MimeEntry::new(Compressible, Text)),
+        ("application/cwl+json", MimeEntry::new(Compressible, Text)),
+        ("application/mbms-register-response+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.notesslide+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.gmop+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml-dialog-fax-sendrecv+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.hal+json", MimeEntry::new(Compressible, Text)),
+        ("application/oebps-package+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.sharedstrings+xml", MimeEntry::new(Compressible, Text)),
+        ("application/emergencycalldata.deviceinfo+xml", MimeEntry::new(Compressible, Text)),
+        ("font/woff2", MimeEntry::new(Unknown, Font)),
+        ("application/city+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.aplextor.warrp+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.slidemaster+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.marlin.drm.conftoken+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oftn.l10n+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.pivotcacherecords+xml", MimeEntry::new(Compressible, Text)),
+        ("application/alto-updatestreamparams+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp-prose+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.tablesinglecells+xml", MimeEntry::new(Compressible, Text)),
+        ("application/zip", MimeEntry::new(Incompressible, Generic)),
+        ("application/vnd.etsi.tsl+xml", MimeEntry::new(Compressible, Text)),
+        ("application/poc-settings+xml", MimeEntry::new(Compressible, Text)),
+        ("application/fhir+json", MimeEntry::new(Compressible, Text)),
+        ("application/ld+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.uoml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oipf.mippvcontrolmessage+xml", MimeEntry::new(Compressible, Text)),
+        ("application/atsc-dwd+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml", MimeEntry::new(Compressible, Text)),
+        ("application/mods+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.poc.final-report+xml", MimeEntry::new(Compressible, Text)),
+        ("application/reputon+json", MimeEntry::new(Compressible, Text)),
+        ("application/senml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.ms-printdevicecapabilities+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xml-patch+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml-dialog-speech+xml", MimeEntry::new(Compressible, Text)),
+        ("application/senml+json", MimeEntry::new(Compressible, Text)),
+        ("application/media_control+xml", MimeEntry::new(Compressible, Text)),
+        ("application/epp+xml", MimeEntry::new(Compressible, Text)),
+        ("application/x-virtualbox-ova", MimeEntry::new(Compressible, Generic)),
+        ("application/vnd.etsi.simservs+xml", MimeEntry::new(Compressible, Text)),
+        ("application/alto-cdni+json", MimeEntry::new(Compressible, Text)),
+        ("message/rfc822", MimeEntry::new(Compressible, Generic)),
+        ("application/vnd.ims.lti.v2.toolproxy.id+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.eu.kasparian.car+json", MimeEntry::new(Compressible, Text)),
+        ("application/docbook+xml", MimeEntry::new(Compressible, Text)),
+        ("application/yang-patch+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.wv.csp+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.balsamiq.bmml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/cnrp+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xenc+xml", MimeEntry::new(Compressible, Text)),
+        ("text/vcard", MimeEntry::new(Compressible, Text)),
+        ("audio/mpeg", MimeEntry::new(Incompressible, Generic)),
+        ("application/vnd.restful+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.wordprocessingml.template.main+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mid-call+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xcap-el+xml", MimeEntry::new(Compressible, Text)),
+        ("application/rdf+xml", MimeEntry::new(Compressible, Text)),
+        ("text/jsx", MimeEntry::new(Compressible, Text)),
+        ("application/ttml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/x-javascript", MimeEntry::new(Compressible, Generic)),
+        ("application/mbms-msk-response+xml", MimeEntry::new(Compressible, Text)),
+        ("application/voucher-cms+json", MimeEntry::new(Compressible, Text)),
+        ("application/mets+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.otps.ct-kip+xml", MimeEntry::new(Compressible, Text)),
+        ("x-shader/x-vertex", MimeEntry::new(Compressible, Generic)),
+        ("application/emotionml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.xacml+json", MimeEntry::new(Compressible, Text)),
+        ("application/ppsp-tracker+json", MimeEntry::new(Compressible, Text)),
+        ("application/shf+xml", MimeEntry::new(Compressible, Text)),
+        ("model/gltf-binary", MimeEntry::new(Compressible, Generic)),
+        ("text/x-markdown", MimeEntry::new(Compressible, Text)),
+        ("text/javascript", MimeEntry::new(Compressible, Text)),
+        ("text/mdx", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.calcchain+xml", MimeEntry::new(Compressible, Text)),
+        ("application/wspolicy+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.querytable+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.poc.optimized-progress-report+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.ussd+xml", MimeEntry::new(Compressible, Text)),
+        ("application/jwk+json", MimeEntry::new(Compressible, Text)),
+        ("application/mbms-schedule+xml", MimeEntry::new(Compressible, Text)),
+        ("application/atsc-rdt+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.wordprocessingml.websettings+xml", MimeEntry::new(Compressible, Text)),
+        ("application/pskc+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.bcast.sgdd+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.nokia.landmarkcollection+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.llamagraphics.life-balance.exchange+xml", MimeEntry::new(Compressible, Text)),
+        ("text/x-jquery-tmpl", MimeEntry::new(Compressible, Text)),
+        ("application/dart", MimeEntry::new(Compressible, Generic)),
+        ("application/mmt-aei+xml", MimeEntry::new(Compressible, Text)),
+        ("application/omdoc+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.iptc.g2.packageitem+xml", MimeEntry::new(Compressible, Text)),
+        ("application/load-control+xml", MimeEntry::new(Compressible, Text)),
+        ("application/sru+xml", MimeEntry::new(Compressible, Text)),
+        ("application/emergencycalldata.cap+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml-conf+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcptt-floor-request+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.drive+json", MimeEntry::new(Compressible, Text)),
+        ("application/mbms-msk+xml", MimeEntry::new(Compressible, Text)),
+        ("application/alto-propmapparams+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.emclient.accessrequest+xml", MimeEntry::new(Compressible, Text)),
+        ("model/gltf+json", MimeEntry::new(Compressible, Text)),
+        ("application/gzip", MimeEntry::new(Incompressible, Generic)),
+        ("application/atsc-rsat+xml", MimeEntry::new(Compressible, Text)),
+        ("application/octet-stream", MimeEntry::new(Incompressible, Generic)),
+        ("application/vnd.oma.cab-user-prefs+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.dvb.notif-container+xml", MimeEntry::new(Compressible, Text)),
+        ("application/x-virtualbox-vdi", MimeEntry::new(Compressible, Generic)),
+        ("application/aif+json", MimeEntry::new(Compressible, Text)),
+        ("application/csta+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.iptvcommand+xml", MimeEntry::new(Compressible, Text)),
+        ("application/activity+json", MimeEntry::new(Compressible, Text)),
+        ("application/atomdeleted+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml-audit-conn+xml", MimeEntry::new(Compressible, Text)),
+        ("application/senml-etch+json", MimeEntry::new(Compressible, Text)),
+        ("text/vtt", MimeEntry::new(Compressible, Text)),
+        ("image/png", MimeEntry::new(Incompressible, Generic)),
+        ("application/vnd.oma.bcast.smartcard-trigger+xml", MimeEntry::new(Compressible, Text)),
+        ("application/provenance+xml", MimeEntry::new(Compressible, Text)),
+        ("application/geoxacml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcvideo-ue-config+xml", MimeEntry::new(Compressible, Text)),
+        ("application/dash-patch+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oipf.spdiscovery+xml", MimeEntry::new(Compressible, Text)),
+        ("application/x-virtualbox-vmdk", MimeEntry::new(Compressible, Generic)),
+        ("application/wasm", MimeEntry::new(Compressible, Generic)),
+        ("application/mbms-protection-description+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.custom-properties+xml", MimeEntry::new(Compressible, Text)),
+        ("application/problem+json", MimeEntry::new(Compressible, Text)),
+        ("application/soap+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oipf.dae.svg+xml", MimeEntry::new(Compressible, Text)),
+        ("application/atomsvc+xml", MimeEntry::new(Compressible, Text)),
+        ("text/cache-manifest", MimeEntry::new(Compressible, Text)),
+        ("x-shader/x-fragment", MimeEntry::new(Compressible, Generic)),
+        ("application/alto-networkmapfilter+json", MimeEntry::new(Compressible, Text)),
+        ("application/x-virtualbox-ovf", MimeEntry::new(Compressible, Generic)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.pivottable+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.irepository.package+xml", MimeEntry::new(Compressible, Text)),
+        ("application/p2p-overlay+xml", MimeEntry::new(Compressible, Text)),
+        ("application/rsd+xml", MimeEntry::new(Compressible, Text)),
+        ("font/otf", MimeEntry::new(Compressible, Font)),
+        ("application/vnd.openxmlformats-officedocument.drawingml.chartshapes+xml", MimeEntry::new(Compressible, Text)),
+        ("application/alto-cdnifilter+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openstreetmap.data+xml", MimeEntry::new(Compressible, Text)),
+        ("application/tar", MimeEntry::new(Compressible, Generic)),
+        ("application/pdf", MimeEntry::new(Incompressible, Generic)),
+        ("application/vnd.route66.link66+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.leap+json", MimeEntry::new(Compressible, Text)),
+        ("model/vnd.moml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcdata-affiliation-command+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.ims.lti.v2.toolconsumerprofile+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.drm.risd+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oipf.contentaccessstreaming+xml", MimeEntry::new(Compressible, Text)),
+        ("application/tei+xml", MimeEntry::new(Compressible, Text)),
+        ("application/x-virtualbox-vhd", MimeEntry::new(Compressible, Generic)),
+        ("application/alto-networkmap+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.iptc.g2.catalogitem+xml", MimeEntry::new(Compressible, Text)),
+        ("application/srgs+xml", MimeEntry::new(Compressible, Text)),
+        ("application/ccxml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/kpml-request+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.pstn+xml", MimeEntry::new(Compressible, Text)),
+        ("application/taxii+json", MimeEntry::new(Compressible, Text)),
+        ("application/x-virtualbox-vbox", MimeEntry::new(Compressible, Generic)),
+        ("text/xml", MimeEntry::new(Compressible, Text)),
+        ("application/alto-propmap+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.las.las+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.revisionlog+xml", MimeEntry::new(Compressible, Text)),
+        ("application/raml+yaml", MimeEntry::new(Compressible, Generic)),
+        ("application/sensml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.hc+json", MimeEntry::new(Compressible, Text)),
+        ("application/jwk-set+json", MimeEntry::new(Compressible, Text)),
+        ("application/dskpp+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcdata-user-profile+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.iptvsad-bc+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.drawingml.diagramdata+xml", MimeEntry::new(Compressible, Text)),
+        ("text/x-gwt-rpc", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.ims.lti.v2.toolsettings+json", MimeEntry::new(Compressible, Text)),
+        ("application/kpml-response+xml", MimeEntry::new(Compressible, Text)),
+        ("text/csv", MimeEntry::new(Compressible, Text)),
+        ("application/samlmetadata+xml", MimeEntry::new(Compressible, Text)),
+        ("image/gif", MimeEntry::new(Incompressible, Generic)),
+        ("application/vnd.dece.ttml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.mason+json", MimeEntry::new(Compressible, Text)),
+        ("application/odm+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp2.bcmcsinfo+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.iptc.g2.knowledgeitem+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.criticaltools.wbs+xml", MimeEntry::new(Compressible, Text)),
+        ("application/csvm+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.bekitzur-stech+json", MimeEntry::new(Compressible, Text)),
+        ("application/rtf", MimeEntry::new(Compressible, Generic)),
+        ("application/beep+xml", MimeEntry::new(Compressible, Text)),
+        ("application/voicexml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.syncml.dm+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.dialogsheet+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.customxmlproperties+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.think-cell.ppttc+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.nokia.n-gage.ac+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.iptc.g2.newsitem+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.byu.uapi+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.bcast.drm-trigger+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.apple.installer+xml", MimeEntry::new(Compressible, Text)),
+        ("application/ibe-pkg-reply+xml", MimeEntry::new(Compressible, Text)),
+        ("application/manifest+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcptt-affiliation-command+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.nokia.landmark+xml", MimeEntry::new(Compressible, Text)),
+        ("application/ssdl+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml-audit-conf+xml", MimeEntry::new(Compressible, Text)),
+        ("application/rlmi+xml", MimeEntry::new(Compressible, Text)),
+        ("application/emma+xml", MimeEntry::new(Compressible, Text)),
+        ("application/route-s-tsid+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.capasystems-pg+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.yamaha.openscoreformat.osfpvg+xml", MimeEntry::new(Compressible, Text)),
+        ("model/x3d+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.ms-opentype", MimeEntry::new(Compressible, Generic)),
+        ("application/vnd.ms-office.activex+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml", MimeEntry::new(Compressible, Text)),
+        ("application/x-web-app-manifest+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.bcast.associated-procedure-parameter+xml", MimeEntry::new(Compressible, Text)),
+        ("application/lgr+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.scidm.messages+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.collection+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.tableschema+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcvideo-affiliation-command+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.slidelayout+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xcap-ns+xml", MimeEntry::new(Compressible, Text)),
+        ("application/scim+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.iptvdiscovery+xml", MimeEntry::new(Compressible, Text)),
+        ("application/cda+xml", MimeEntry::new(Compressible, Text)),
+        ("image/bmp", MimeEntry::new(Compressible, Generic)),
+        ("model/vnd.collada+xml", MimeEntry::new(Compressible, Text)),
+        ("application/sensml+json", MimeEntry::new(Compressible, Text)),
+        ("application/cstadata+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.dd2+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.bsf+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xhtml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/gpx+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.comments+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcvideo-user-profile+xml", MimeEntry::new(Compressible, Text)),
+        ("application/urc-ressheet+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.theme+xml", MimeEntry::new(Compressible, Text)),
+        ("image/svg+xml", MimeEntry::new(Compressible, Text)),
+        ("application/metalink4+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.poc.groups+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xaml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/metalink+xml", MimeEntry::new(Compressible, Text)),
+        ("application/fdt+xml", MimeEntry::new(Compressible, Text)),
+        ("text/rtf", MimeEntry::new(Compressible, Text)),
+        ("application/jscalendar+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcptt-info+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.bcast.imd+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.pivotcachedefinition+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.ims.lis.v2.result+json", MimeEntry::new(Compressible, Text)),
+        ("application/patch-ops-error+xml", MimeEntry::new(Compressible, Text)),
+        ("application/td+json", MimeEntry::new(Compressible, Text)),
+        ("application/xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml-dialog-group+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xliff+xml", MimeEntry::new(Compressible, Text)),
+        ("text/css", MimeEntry::new(Compressible, Text)),
+        ("application/urc-grpsheet+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.gov.sk.e-form+xml", MimeEntry::new(Compressible, Text)),
+        ("application/msc-mixer+xml", MimeEntry::new(Compressible, Text)),
+        ("application/yang-patch+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.notesmaster+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.1000minds.decision-model+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.cab-address-book+xml", MimeEntry::new(Compressible, Text)),
+        ("application/cea-2018+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.micro+json", MimeEntry::new(Compressible, Text)),
+        ("application/route-usd+xml", MimeEntry::new(Compressible, Text)),
+        ("application/yin+xml", MimeEntry::new(Compressible, Text)),
+        ("application/thraud+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.syncml.dmddf+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.lwm2m+json", MimeEntry::new(Compressible, Text)),
+        ("application/3gpphal+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.marlin.drm.actiontoken+xml", MimeEntry::new(Compressible, Text)),
+        ("application/x-dtbresource+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcptt-ue-init-config+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.infotech.project+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/urc-uisocketdesc+xml", MimeEntry::new(Compressible, Text)),
+        ("image/vnd.ms-dds", MimeEntry::new(Compressible, Generic)),
+        ("application/opc-nodeset+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcvideo-transmission-request+xml", MimeEntry::new(Compressible, Text)),
+        ("application/yang-data+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.nokia.conml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.cyclonedx+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.sycle+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.slideshow.main+xml", MimeEntry::new(Compressible, Text)),
+        ("application/ecmascript", MimeEntry::new(Compressible, Text)),
+        ("application/xv+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcptt-location-info+xml", MimeEntry::new(Compressible, Text)),
+        ("application/resource-lists+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.miele+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.syft+json", MimeEntry::new(Compressible, Text)),
+        ("application/nlsml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/resource-lists-diff+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.table+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.amadeus+json", MimeEntry::new(Compressible, Text)),
+        ("text/richtext", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.ms-playready.initiator+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.omads-file+xml", MimeEntry::new(Compressible, Text)),
+        ("application/calendar+xml", MimeEntry::new(Compressible, Text)),
+        ("application/x-sh", MimeEntry::new(Compressible, Generic)),
+        ("application/sarif-external-properties+json", MimeEntry::new(Compressible, Text)),
+        ("application/jrd+json", MimeEntry::new(Compressible, Text)),
+        ("text/yaml", MimeEntry::new(Compressible, Text)),
+        ("application/lost+xml", MimeEntry::new(Compressible, Text)),
+        ("application/framework-attributes+xml", MimeEntry::new(Compressible, Text)),
+        ("application/swid+xml", MimeEntry::new(Compressible, Text)),
+        ("application/atom+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.moml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/clue+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.gov.sk.xmldatacontainer+xml", MimeEntry::new(Compressible, Text)),
+        ("application/problem+xml", MimeEntry::new(Compressible, Text)),
+        ("application/mrb-consumer+xml", MimeEntry::new(Compressible, Text)),
+        ("application/emergencycalldata.subscriberinfo+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.heroku+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.hyper+json", MimeEntry::new(Compressible, Text)),
+        ("application/alto-error+json", MimeEntry::new(Compressible, Text)),
+        ("application/watcherinfo+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.cug+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.cab-feature-handler+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml-dialog+xml", MimeEntry::new(Compressible, Text)),
+        ("application/mbms-associated-procedure-description+xml", MimeEntry::new(Compressible, Text)),
+        ("application/mathml-content+xml", MimeEntry::new(Compressible, Text)),
+        ("application/mbms-deregister+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.revisionheaders+xml", MimeEntry::new(Compressible, Text)),
+        ("application/alto-endpointpropparams+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcptt-service-config+xml", MimeEntry::new(Compressible, Text)),
+        ("application/hl7v2+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.srvcc-ext+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp-prose-pc3ch+xml", MimeEntry::new(Compressible, Text)),
+        ("application/reginfo+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.chartsheet+xml", MimeEntry::new(Compressible, Text)),
+        ("application/ace+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.presprops+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.chemdraw+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xproc+xml", MimeEntry::new(Compressible, Text)),
+        ("application/x-httpd-php", MimeEntry::new(Compressible, Generic)),
+        ("application/dash+xml", MimeEntry::new(Compressible, Text)),
+        ("application/elm+json", MimeEntry::new(Compressible, Text)),
+        ("application/x-virtualbox-hdd", MimeEntry::new(Compressible, Generic)),
+        ("application/mrb-publish+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xspf+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.gnu.taler.exchange+json", MimeEntry::new(Compressible, Text)),
+        ("application/x-dtbook+xml", MimeEntry::new(Compressible, Text)),
+        ("application/mbms-envelope+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.bcast.sprov+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xhtml-voice+xml", MimeEntry::new(Compressible, Text)),
+        ("application/conference-info+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.ms-printing.printticket+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcdata-ue-config+xml", MimeEntry::new(Compressible, Text)),
+        ("application/x-www-form-urlencoded", MimeEntry::new(Compressible, Generic)),
+        ("application/vnd.eclipse.ditto+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oipf.dae.xhtml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.adobe.xdp+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.hal+xml", MimeEntry::new(Compressible, Text)),
+        ("application/mud+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcdata-msgstore-ctrl-request+xml", MimeEntry::new(Compressible, Text)),
+        ("application/expect-ct-report+json", MimeEntry::new(Compressible, Text)),
+        ("application/emergencycalldata.comment+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xslt+xml", MimeEntry::new(Compressible, Text)),
+        ("application/dssc+xml", MimeEntry::new(Compressible, Text)),
+        ("text/calender", MimeEntry::new(Compressible, Text)),
+        ("application/rfc+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.bcast.notification+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.dvb.notif-generic+xml", MimeEntry::new(Compressible, Text)),
+        ("application/wsdl+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.xmi+xml", MimeEntry::new(Compressible, Text)),
+        ("application/jsonml+json", MimeEntry::new(Compressible, Text)),
+        ("application/emergencycalldata.providerinfo+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.pwg-xhtml-print+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.template.main+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.amundsen.maze+xml", MimeEntry::new(Compressible, Text)),
+        ("application/clue_info+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.avalon+json", MimeEntry::new(Compressible, Text)),
+        ("application/mbms-user-service-description+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.geocube+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.themeoverride+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml-dialog-transform+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.recordare.musicxml+xml", MimeEntry::new(Compressible, Text)),
+        ("text/less", MimeEntry::new(Compressible, Text)),
+        ("application/x-xliff+xml", MimeEntry::new(Compressible, Text)),
+        ("application/atsc-held+xml", MimeEntry::new(Compressible, Text)),
+        ("application/stix+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml-dialog-base+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xmpp+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.sci+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.solent.sdkm+xml", MimeEntry::new(Compressible, Text)),
+        ("application/geo+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.drawingml.diagramlayout+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.geo+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcvideo-service-config+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.dm.delegation+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.usernames+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oipf.userprofile+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.wordprocessingml.endnotes+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.dvb.notif-ia-registration-response+xml", MimeEntry::new(Compressible, Text)),
+        ("application/alto-updatestreamcontrol+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.eszigno3+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.las.las+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xop+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.wv.ssp+xml", MimeEntry::new(Compressible, Text)),
+        ("application/alto-endpointprop+json", MimeEntry::new(Compressible, Text)),
+        ("text/tab-separated-values", MimeEntry::new(Compressible, Text)),
+        ("application/x-dtbncx+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oipf.ueprofile+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.dart", MimeEntry::new(Compressible, Generic)),
+        ("application/vnd.collection.next+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcptt-user-profile+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.ms-fontobject", MimeEntry::new(Compressible, Font)),
+        ("application/samlassertion+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xcap-caps+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.sheetmetadata+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.srvcc-info+xml", MimeEntry::new(Compressible, Text)),
+        ("application/ccmp+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vcard+json", MimeEntry::new(Compressible, Text)),
+        ("application/xcon-conference-info-diff+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.dataresource+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.iptc.g2.newsmessage+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcptt-signed+xml", MimeEntry::new(Compressible, Text)),
+        ("message/imdn+xml", MimeEntry::new(Compressible, Text)),
+        ("application/dns+json", MimeEntry::new(Compressible, Text)),
+        ("application/x-tar", MimeEntry::new(Compressible, Generic)),
+        ("application/mads+xml", MimeEntry::new(Compressible, Text)),
+        ("application/mediaservercontrol+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.cab-pcc+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.gnu.taler.merchant+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.aoc+xml", MimeEntry::new(Compressible, Text)),
+        ("text/uri-list", MimeEntry::new(Compressible, Text)),
+        ("text/x-org", MimeEntry::new(Compressible, Text)),
+        ("application/davmount+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.syncml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/sep+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.drawingml.diagramcolors+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.poc.group-advertisement+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcvideo-info+xml", MimeEntry::new(Compressible, Text)),
+        ("application/urc-targetdesc+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml", MimeEntry::new(Compressible, Text)),
+        ("application/spirits-event+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.futoin+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml-audit-dialog+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.dvb.notif-aggregate-root+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.ims.lti.v2.toolsettings.simple+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.sms+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.shootproof+json", MimeEntry::new(Compressible, Text)),
+        ("application/mbms-reception-report+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.nokia.iptv.config+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openblox.game+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.connections+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.software602.filler.form+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.ms-printschematicket+xml", MimeEntry::new(Compressible, Text)),
+        ("application/toml", MimeEntry::new(Compressible, Generic)),
+        ("application/fhir+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.cryptii.pipe+json", MimeEntry::new(Compressible, Text)),
+        ("application/jf2feed+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.hyper-item+json", MimeEntry::new(Compressible, Text)),
+        ("font/ttf", MimeEntry::new(Compressible, Font)),
+        ("application/prs.xsf+xml", MimeEntry::new(Compressible, Text)),
+        ("application/pls+xml", MimeEntry::new(Compressible, Text)),
+        ("application/lostsync+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.mozilla.xul+xml", MimeEntry::new(Compressible, Text)),
+        ("application/inkml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-package.relationships+xml", MimeEntry::new(Compressible, Text)),
+        ("application/calendar+json", MimeEntry::new(Compressible, Text)),
+        ("application/mathml-presentation+xml", MimeEntry::new(Compressible, Text)),
+        ("application/auth-policy+xml", MimeEntry::new(Compressible, Text)),
+        ("application/cpl+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml-audit+xml", MimeEntry::new(Compressible, Text)),
+        ("application/rdap+json", MimeEntry::new(Compressible, Text)),
+        ("application/alto-directory+json", MimeEntry::new(Compressible, Text)),
+        ("application/held+xml", MimeEntry::new(Compressible, Text)),
+        ("text/x-processing", MimeEntry::new(Compressible, Text)),
+        ("application/jose+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.wordprocessingml.footer+xml", MimeEntry::new(Compressible, Text)),
+        ("application/marcxml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/media-policy-dataset+xml", MimeEntry::new(Compressible, Text)),
+        ("image/x-icon", MimeEntry::new(Compressible, Generic)),
+        ("application/xcap-error+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.liberty-request+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.pal+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.artisan+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.poc.invocation-descriptor+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.drawingml.diagramstyle+xml", MimeEntry::new(Compressible, Text)),
+        ("text/cmd", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oci.image.manifest.v1+json", MimeEntry::new(Compressible, Text)),
+        ("application/yang-data+xml", MimeEntry::new(Compressible, Text)),
+        ("application/emergencycalldata.serviceinfo+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.syncml.dmtnds+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.wordprocessingml.footnotes+xml", MimeEntry::new(Compressible, Text)),
+        ("application/sarif+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.omads-folder+xml", MimeEntry::new(Compressible, Text)),
+        ("application/postscript", MimeEntry::new(Compressible, Generic)),
+        ("application/vnd.pagerduty+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.dvb.notif-ia-registration-request+xml", MimeEntry::new(Compressible, Text)),
+        ("application/ibe-key-request+xml", MimeEntry::new(Compressible, Text)),
+        ("application/elm+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcdata-service-config+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.tablestyles+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oracle.resource+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.wordprocessingml.comments+xml", MimeEntry::new(Compressible, Text)),
+        ("image/x-ms-bmp", MimeEntry::new(Compressible, Generic)),
+        ("application/coap-group+json", MimeEntry::new(Compressible, Text)),
+        ("application/vcard+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.handoutmaster+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcvideo-location-info+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.marlin.drm.license+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.slide+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.viewprops+xml", MimeEntry::new(Compressible, Text)),
+        ("text/x-suse-ymp", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oma.xcap-directory+xml", MimeEntry::new(Compressible, Text)),
+        ("application/3gpp-ims+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.commentauthors+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oipf.contentaccessdownload+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xcon-conference-info+xml", MimeEntry::new(Compressible, Text)),
+        ("text/markdown", MimeEntry::new(Compressible, Text)),
+        ("application/webpush-options+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.wordprocessingml.document.glossary+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcvideo-mbms-usage-info+xml", MimeEntry::new(Compressible, Text)),
+        ("application/captive+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.citationstyles.style+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.avistar+xml", MimeEntry::new(Compressible, Text)),
+        ("application/3gpdash-qoe-report+xml", MimeEntry::new(Compressible, Text)),
+        ("application/sparql-results+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.apothekende.reservation+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.cyclonedx+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcdata-info+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.vel+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.dvb.dvbisl+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.hyperdrive+json", MimeEntry::new(Compressible, Text)),
+        ("application/3gpphalforms+json", MimeEntry::new(Compressible, Text)),
+        ("application/pvd+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.drawingml.chart+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcdata-regroup+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.dvb.notif-ia-msglist+xml", MimeEntry::new(Compressible, Text)),
+        ("application/alto-costmap+json", MimeEntry::new(Compressible, Text)),
+        ("application/emergencycalldata.control+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.coreos.ignition+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.slideupdateinfo+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.sun.wadl+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.radisys.msml-audit-stream+xml", MimeEntry::new(Compressible, Text)),
+        ("application/dialog-info+xml", MimeEntry::new(Compressible, Text)),
+        ("application/mathml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.template.main+xml", MimeEntry::new(Compressible, Text)),
+        ("application/pidf+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.zzazz.deck+xml", MimeEntry::new(Compressible, Text)),
+        ("application/pidf-diff+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xacml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.externallink+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.bbf.usp.msg+json", MimeEntry::new(Compressible, Text)),
+        ("application/javascript", MimeEntry::new(Compressible, Text)),
+        ("application/scaip+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-package.core-properties+xml", MimeEntry::new(Compressible, Text)),
+        ("application/merge-patch+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.nacamar.ybrid+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.oipf.spdlist+xml", MimeEntry::new(Compressible, Text)),
+        ("application/mmt-usd+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.iptc.g2.conceptitem+xml", MimeEntry::new(Compressible, Text)),
+        ("application/json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.document+json", MimeEntry::new(Compressible, Text)),
+        ("application/json-patch+json", MimeEntry::new(Compressible, Text)),
+        ("application/msc-ivr+xml", MimeEntry::new(Compressible, Text)),
+        ("application/route-apd+xml", MimeEntry::new(Compressible, Text)),
+        ("application/smil+xml", MimeEntry::new(Compressible, Text)),
+        ("application/im-iscomposing+xml", MimeEntry::new(Compressible, Text)),
+        ("application/dicom+xml", MimeEntry::new(Compressible, Text)),
+        ("text/plain", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.iptvsad-npvr+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.state-and-event-info+xml", MimeEntry::new(Compressible, Text)),
+        ("application/x-abiword", MimeEntry::new(Unknown, Generic)),
+        ("application/alto-endpointcostparams+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.wordprocessingml.fonttable+xml", MimeEntry::new(Compressible, Text)),
+        ("application/x-rar-compressed", MimeEntry::new(Incompressible, Generic)),
+        ("application/vnd.oma.poc.detailed-progress-report+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.mcptt-mbms-usage-info+xml", MimeEntry::new(Compressible, Text)),
+        ("application/alto-costmapfilter+json", MimeEntry::new(Compressible, Text)),
+        ("image/jpeg", MimeEntry::new(Incompressible, Generic)),
+        ("application/vnd.iptc.g2.planningitem+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.collection.doc+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.wordprocessingml.settings+xml", MimeEntry::new(Compressible, Text)),
+        ("application/cellml+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.informedcontrol.rms+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.volatiledependencies+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.veritone.aion+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.nearst.inv+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.fujifilm.fb.jfi+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml", MimeEntry::new(Compressible, Text)),
+        ("application/xcap-diff+xml", MimeEntry::new(Compressible, Text)),
+        ("model/step+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.iptvsync+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.3gpp.access-transfer-events+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.seis+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.ims.lti.v2.toolproxy+json", MimeEntry::new(Compressible, Text)),
+        ("image/vnd.adobe.photoshop", MimeEntry::new(Compressible, Generic)),
+        ("application/vnd.etsi.iptvsad-cod+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.gentics.grd+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.etsi.overload-control-policy-dataset+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.dvb.notif-init+xml", MimeEntry::new(Compressible, Text)),
+        ("application/emergencycalldata.veds+xml", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.tmd.mediaflex.api+xml", MimeEntry::new(Compressible, Text)),
+        ("application/alto-endpointcost+json", MimeEntry::new(Compressible, Text)),
+        ("application/vnd.ctct.ws+xml", MimeEntry::new(Compressible, Text)),
     ],
-} };
+};
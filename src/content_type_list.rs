@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+use crate::ContentTypeRule;
+
+/// User-supplied `Content-Type` allow & deny entries, compiled for matching.
+///
+/// Entries are either exact MIME types, e.g. `application/vnd.ourco.report`,
+/// or patterns ending with `*`, e.g. `application/vnd.ourco.*`, matching any type with that prefix.
+/// Matching is case insensitive.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ContentTypeLists {
+    allow: HashSet<String>,
+    deny: HashSet<String>,
+    /// The prefixes of `*` patterns.
+    allow_patterns: Vec<String>,
+    deny_patterns: Vec<String>,
+}
+
+impl ContentTypeLists {
+    pub(crate) fn new(allow: &[String], deny: &[String]) -> Self {
+        let mut lists = Self::default();
+        for entry in allow {
+            insert(entry, &mut lists.allow, &mut lists.allow_patterns);
+        }
+        for entry in deny {
+            insert(entry, &mut lists.deny, &mut lists.deny_patterns);
+        }
+        lists
+    }
+
+    /// Matches a MIME type essence, e.g. `text/html`, against the entries.
+    ///
+    /// Exact entries are checked before patterns, and within each, deny entries before allow entries.
+    /// Returns `None` if no entry matches.
+    pub(crate) fn rule(&self, essence: &str) -> Option<ContentTypeRule> {
+        if self.allow.is_empty()
+            && self.deny.is_empty()
+            && self.allow_patterns.is_empty()
+            && self.deny_patterns.is_empty()
+        {
+            return None;
+        }
+        let essence = essence.to_ascii_lowercase();
+        if self.deny.contains(&essence) {
+            Some(ContentTypeRule::Denied)
+        } else if self.allow.contains(&essence) {
+            Some(ContentTypeRule::Allowed)
+        } else if matches_any(&self.deny_patterns, &essence) {
+            Some(ContentTypeRule::Denied)
+        } else if matches_any(&self.allow_patterns, &essence) {
+            Some(ContentTypeRule::Allowed)
+        } else {
+            None
+        }
+    }
+}
+
+fn insert(entry: &str, exact: &mut HashSet<String>, patterns: &mut Vec<String>) {
+    let entry = entry.trim().to_ascii_lowercase();
+    match entry.strip_suffix('*') {
+        Some(prefix) => patterns.push(prefix.to_owned()),
+        None => {
+            exact.insert(entry);
+        }
+    }
+}
+
+fn matches_any(patterns: &[String], essence: &str) -> bool {
+    patterns.iter().any(|prefix| essence.starts_with(prefix))
+}
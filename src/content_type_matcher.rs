@@ -0,0 +1,79 @@
+/// Rules matching compressible `Content-Type`s (MIME) without regular expressions,
+/// see [`CompressMiddlewareBuilder::content_type_matcher`](crate::CompressMiddlewareBuilder::content_type_matcher).
+///
+/// Rules are matched against the essence of the type, e.g. `text/html` of `text/html; charset=utf-8`.
+/// Matching is case insensitive. A type matching any rule is compressible.
+///
+/// ## Example
+/// ```rust
+/// # async_std::task::block_on(async {
+/// use tide_compress::ContentTypeMatcher;
+///
+/// let mut app = tide::new();
+///
+/// app.with(
+///     tide_compress::CompressMiddleware::builder()
+///         .content_type_matcher(Some(
+///             ContentTypeMatcher::standard()
+///                 .exact("application/x-abiword")
+///                 .prefix("application/vnd.ourco."),
+///         ))
+///         .build(),
+/// );
+/// # })
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContentTypeMatcher {
+    /// Types matched exactly, e.g. `application/javascript`.
+    pub exact: Vec<String>,
+    /// Prefixes of matched types, e.g. `text/`.
+    pub prefixes: Vec<String>,
+    /// Suffixes of matched types, e.g. `+json`.
+    pub suffixes: Vec<String>,
+}
+
+impl ContentTypeMatcher {
+    /// Creates a matcher without any rules, which matches nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a matcher with the rules of the default `content_type_check` regular expression, `^text/|\+(?:json|text|xml)$`:
+    /// the `text/` prefix, and the `+json`, `+text` & `+xml` suffixes.
+    pub fn standard() -> Self {
+        Self::new()
+            .prefix("text/")
+            .suffix("+json")
+            .suffix("+text")
+            .suffix("+xml")
+    }
+
+    /// Adds a type to match exactly.
+    pub fn exact(mut self, essence: impl Into<String>) -> Self {
+        self.exact.push(essence.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Adds a prefix of types to match.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefixes.push(prefix.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Adds a suffix of types to match.
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffixes.push(suffix.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Whether a MIME type essence matches any rule.
+    pub fn is_match(&self, essence: &str) -> bool {
+        let essence = essence.to_ascii_lowercase();
+        self.exact.contains(&essence)
+            || self
+                .prefixes
+                .iter()
+                .any(|prefix| essence.starts_with(prefix))
+            || self.suffixes.iter().any(|suffix| essence.ends_with(suffix))
+    }
+}
@@ -0,0 +1,46 @@
+//! Telling the kind of data of a MIME type from its name.
+//!
+//! `generate-database` includes this file to record the kind of data of each type in the MIME database,
+//! so it has no dependencies on the rest of the crate.
+
+/// The kind of data a MIME type holds, for tuning encoders.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DataKind {
+    Generic,
+    Text,
+    Font,
+}
+
+/// Font types, besides the `font/` top-level type and `font-` & `x-font-` subtypes.
+const FONT_TYPES: &[&str] = &["application/vnd.ms-fontobject"];
+
+/// Text types, besides the `text/` top-level type and `+json` & `+xml` suffixes.
+const TEXT_TYPES: &[&str] = &[
+    "application/ecmascript",
+    "application/javascript",
+    "application/json",
+    "application/xml",
+];
+
+/// The kind of data of a MIME type essence, e.g. `text/html`.
+pub(crate) fn data_kind(essence: &str) -> DataKind {
+    let (basetype, subtype) = match essence.split_once('/') {
+        Some(parts) => parts,
+        None => return DataKind::Generic,
+    };
+    if basetype == "font"
+        || subtype.starts_with("font-")
+        || subtype.starts_with("x-font-")
+        || FONT_TYPES.contains(&essence)
+    {
+        DataKind::Font
+    } else if basetype == "text"
+        || subtype.ends_with("+json")
+        || subtype.ends_with("+xml")
+        || TEXT_TYPES.contains(&essence)
+    {
+        DataKind::Text
+    } else {
+        DataKind::Generic
+    }
+}
@@ -0,0 +1,126 @@
+use std::fmt;
+
+use tide::http::content::Encoding;
+
+use crate::SkipReason;
+
+/// The name of the header carrying the decision, see [`DebugDecision::Header`].
+pub const DECISION_HEADER: &str = "X-Compression-Decision";
+
+/// Whether the [`CompressionDecision`] is attached to responses, for debugging.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DebugDecision {
+    /// Do not attach the decision. The default.
+    #[default]
+    Off,
+    /// Attach the decision as a response extension.
+    Extension,
+    /// Attach the decision as a response extension and as an `X-Compression-Decision` header.
+    Header,
+}
+
+/// Why [`CompressMiddleware`](crate::CompressMiddleware) did or did not compress a response.
+///
+/// Attached to the response as an extension when enabled with [`DebugDecision`].
+///
+/// ## Example
+/// ```rust
+/// # async_std::task::block_on(async {
+/// use tide::http::{headers, Method, Request, Url};
+/// use tide_compress::{CompressionDecision, CompressionOutcome, DebugDecision, SkipReason};
+///
+/// let mut app = tide::new();
+///
+/// app.with(
+///     tide_compress::CompressMiddleware::builder()
+///         .debug_decision(DebugDecision::Extension)
+///         .build(),
+/// );
+/// app.at("/").get(|_| async { Ok("short") });
+///
+/// let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+/// req.insert_header(headers::ACCEPT_ENCODING, "gzip");
+/// let res: tide::http::Response = app.respond(req).await.unwrap();
+///
+/// let decision = res.ext().get::<CompressionDecision>().unwrap();
+/// assert_eq!(decision.outcome, CompressionOutcome::Skipped(SkipReason::BelowThreshold));
+/// assert_eq!(decision.threshold, 1024);
+/// # })
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompressionDecision {
+    /// Whether the response was compressed, and with which encoding, or why not.
+    pub outcome: CompressionOutcome,
+    /// The minimum body size threshold in effect.
+    pub threshold: usize,
+    /// The rule the `Content-Type` matched, if it was checked.
+    pub content_type_rule: Option<ContentTypeRule>,
+}
+
+/// Whether a response was compressed, see [`CompressionDecision`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressionOutcome {
+    /// Compressed with the encoding.
+    Compressed(Encoding),
+    /// Left uncompressed.
+    Skipped(SkipReason),
+}
+
+/// Which rule the response `Content-Type` matched, see [`CompressionDecision`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ContentTypeRule {
+    /// Matched a user-supplied allow entry, and so is compressible whatever the other rules.
+    Allowed,
+    /// Matched a user-supplied deny entry, and so is not compressible.
+    Denied,
+    /// Listed as compressible in the MIME database.
+    Database,
+    /// Listed as not compressible in the MIME database, and so not compressible whatever the regular expression.
+    Incompressible,
+    /// Matched by the `content_type_check` regular expression.
+    Regex,
+    /// Matched by the `content_type_matcher` rules.
+    Matcher,
+    /// Had no `Content-Type`, and so is not compressible, see [`MissingContentType::Never`](crate::MissingContentType::Never).
+    Missing,
+    /// Had no `Content-Type`, and the start of the body looks like text.
+    SniffedText,
+    /// Had no `Content-Type`, and the start of the body looks binary, and so is not compressible.
+    SniffedBinary,
+    /// Matched no rule, and so is not compressible.
+    Unmatched,
+}
+
+impl fmt::Display for CompressionDecision {
+    /// Formats the decision as the `X-Compression-Decision` header value,
+    /// e.g. `skipped=below-threshold; threshold=1024`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.outcome {
+            CompressionOutcome::Compressed(encoding) => write!(f, "compressed={}", encoding)?,
+            CompressionOutcome::Skipped(reason) => write!(f, "skipped={}", reason.as_str())?,
+        }
+        write!(f, "; threshold={}", self.threshold)?;
+        if let Some(rule) = self.content_type_rule {
+            write!(f, "; content-type={}", rule.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+impl ContentTypeRule {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ContentTypeRule::Allowed => "allowed",
+            ContentTypeRule::Denied => "denied",
+            ContentTypeRule::Database => "database",
+            ContentTypeRule::Incompressible => "incompressible",
+            ContentTypeRule::Regex => "regex",
+            ContentTypeRule::Matcher => "matcher",
+            ContentTypeRule::Missing => "missing",
+            ContentTypeRule::SniffedText => "sniffed-text",
+            ContentTypeRule::SniffedBinary => "sniffed-binary",
+            ContentTypeRule::Unmatched => "unmatched",
+        }
+    }
+}
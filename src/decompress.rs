@@ -0,0 +1,491 @@
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures_lite::io::{AsyncBufRead, AsyncRead, BufReader};
+use tide::http::content::Encoding;
+use tide::http::{headers, Body, StatusCode};
+use tide::{Middleware, Next, Request, Response};
+
+#[cfg(feature = "brotli")]
+use async_compression::futures::bufread::BrotliDecoder;
+#[cfg(feature = "gzip")]
+use async_compression::futures::bufread::GzipDecoder;
+#[cfg(feature = "zstd")]
+use async_compression::futures::bufread::ZstdDecoder;
+#[cfg(feature = "deflate")]
+use async_compression::futures::bufread::{DeflateDecoder, ZlibDecoder};
+#[cfg(feature = "deflate")]
+use futures_lite::ready;
+
+const MAX_SIZE: usize = 10 * 1024 * 1024;
+
+/// A middleware for decompressing request body data.
+///
+/// Requests with a `Content-Encoding` header have their body wrapped in the matching decoder,
+/// and the `Content-Encoding` and `Content-Length` headers removed.
+/// Requests using an encoding which is not supported are rejected with `415 Unsupported Media Type`.
+///
+/// To protect against decompression bombs, the decompressed body is limited in size (Default: 10 MiB)
+/// and optionally in its expansion ratio. Once a limit is exceeded the body stream errors and the
+/// request is answered with `413 Payload Too Large`.
+///
+/// ## Example
+/// ```rust
+/// # async_std::task::block_on(async {
+/// let mut app = tide::new();
+///
+/// app.with(tide_compress::DecompressMiddleware::new());
+/// # })
+/// ```
+#[derive(Clone, Debug)]
+pub struct DecompressMiddleware {
+    max_size: Option<usize>,
+    max_ratio: Option<usize>,
+}
+
+impl Default for DecompressMiddleware {
+    fn default() -> Self {
+        DecompressMiddlewareBuilder::default().into()
+    }
+}
+
+impl DecompressMiddleware {
+    /// Creates a new DecompressMiddleware.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # async_std::task::block_on(async {
+    /// let mut app = tide::new();
+    ///
+    /// app.with(tide_compress::DecompressMiddleware::new());
+    /// # })
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Used to create a new DecompressMiddleware with custom settings.
+    ///
+    /// See [`DecompressMiddlewareBuilder`]
+    pub fn builder() -> DecompressMiddlewareBuilder {
+        DecompressMiddlewareBuilder::new()
+    }
+
+    /// Sets the maximum decompressed body size, in bytes.
+    pub fn set_max_size(&mut self, max_size: Option<usize>) {
+        self.max_size = max_size
+    }
+
+    /// Gets the existing maximum decompressed body size, in bytes.
+    pub fn max_size(&self) -> Option<usize> {
+        self.max_size
+    }
+
+    /// Sets the maximum ratio of decompressed bytes to compressed bytes.
+    pub fn set_max_ratio(&mut self, max_ratio: Option<usize>) {
+        self.max_ratio = max_ratio
+    }
+
+    /// Gets the existing maximum ratio of decompressed bytes to compressed bytes.
+    pub fn max_ratio(&self) -> Option<usize> {
+        self.max_ratio
+    }
+}
+
+#[tide::utils::async_trait]
+impl<State: Clone + Send + Sync + 'static> Middleware<State> for DecompressMiddleware {
+    async fn handle(&self, mut req: Request<State>, next: Next<'_, State>) -> tide::Result {
+        // `ContentEncoding::from_headers()` cannot be used here as it does not handle unknown encodings,
+        // which a client is free to send us.
+        let encodings = match req.header(headers::CONTENT_ENCODING) {
+            Some(values) => {
+                let mut encodings = vec![];
+                for value in values.iter().flat_map(|value| value.as_str().split(',')) {
+                    match parse_encoding(value) {
+                        Some(Encoding::Identity) => {}
+                        Some(encoding) if is_supported(encoding) => encodings.push(encoding),
+                        // Unsupported encoding, as per RFC 9110:
+                        // https://www.rfc-editor.org/rfc/rfc9110.html#name-content-encoding
+                        _ => return Ok(unsupported_media_type()),
+                    }
+                }
+                encodings
+            }
+            None => return Ok(next.run(req).await),
+        };
+
+        // Only `identity` was specified, the body can be left alone.
+        if encodings.is_empty() {
+            req.remove_header(headers::CONTENT_ENCODING);
+            return Ok(next.run(req).await);
+        }
+
+        let progress = Arc::new(Progress::default());
+
+        // Encodings are listed in the order they were applied, so they must be decoded in reverse.
+        let mut body = Body::from_reader(EncodedCounter::new(req.take_body(), &progress), None);
+        for encoding in encodings.iter().rev() {
+            body = get_decoder(body, encoding);
+        }
+        req.set_body(Body::from_reader(
+            BufReader::new(DecodedLimiter::new(
+                body,
+                &progress,
+                self.max_size,
+                self.max_ratio,
+            )),
+            None,
+        ));
+
+        // The body is no longer encoded, and the end size is unknown.
+        req.remove_header(headers::CONTENT_ENCODING);
+        req.remove_header(headers::CONTENT_LENGTH);
+
+        let res = next.run(req).await;
+
+        // The route may have turned the aborted body stream into any sort of error,
+        // but the client should be told what actually happened.
+        if progress.exceeded.load(Ordering::Relaxed) {
+            return Ok(Response::new(StatusCode::PayloadTooLarge));
+        }
+
+        Ok(res)
+    }
+}
+
+/// Shared byte counts between the two ends of a decoder chain.
+#[derive(Debug, Default)]
+struct Progress {
+    encoded: AtomicUsize,
+    decoded: AtomicUsize,
+    exceeded: AtomicBool,
+}
+
+/// Counts the encoded bytes consumed by a decoder.
+#[derive(Debug)]
+struct EncodedCounter<R> {
+    inner: R,
+    progress: Arc<Progress>,
+}
+
+impl<R> EncodedCounter<R> {
+    fn new(inner: R, progress: &Arc<Progress>) -> Self {
+        Self {
+            inner,
+            progress: Arc::clone(progress),
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for EncodedCounter<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let poll = Pin::new(&mut self.inner).poll_read(cx, buf);
+        if let Poll::Ready(Ok(read)) = poll {
+            self.progress.encoded.fetch_add(read, Ordering::Relaxed);
+        }
+        poll
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncBufRead for EncodedCounter<R> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        Pin::new(&mut self.get_mut().inner).poll_fill_buf(cx)
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        self.progress.encoded.fetch_add(amt, Ordering::Relaxed);
+        Pin::new(&mut self.inner).consume(amt)
+    }
+}
+
+/// Counts the decoded bytes produced by a decoder chain, and errors once a limit is exceeded.
+#[derive(Debug)]
+struct DecodedLimiter<R> {
+    inner: R,
+    progress: Arc<Progress>,
+    max_size: Option<usize>,
+    max_ratio: Option<usize>,
+}
+
+impl<R> DecodedLimiter<R> {
+    fn new(
+        inner: R,
+        progress: &Arc<Progress>,
+        max_size: Option<usize>,
+        max_ratio: Option<usize>,
+    ) -> Self {
+        Self {
+            inner,
+            progress: Arc::clone(progress),
+            max_size,
+            max_ratio,
+        }
+    }
+
+    fn exceeds_limits(&self, decoded: usize) -> bool {
+        if let Some(max_size) = self.max_size {
+            if decoded > max_size {
+                return true;
+            }
+        }
+        if let Some(max_ratio) = self.max_ratio {
+            let encoded = self.progress.encoded.load(Ordering::Relaxed);
+            if decoded > encoded.saturating_mul(max_ratio) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for DecodedLimiter<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        if self.progress.exceeded.load(Ordering::Relaxed) {
+            return Poll::Ready(Err(limit_exceeded()));
+        }
+
+        let read = match Pin::new(&mut self.inner).poll_read(cx, buf) {
+            Poll::Ready(Ok(read)) => read,
+            poll => return poll,
+        };
+
+        let decoded = self.progress.decoded.fetch_add(read, Ordering::Relaxed) + read;
+        if self.exceeds_limits(decoded) {
+            self.progress.exceeded.store(true, Ordering::Relaxed);
+            return Poll::Ready(Err(limit_exceeded()));
+        }
+
+        Poll::Ready(Ok(read))
+    }
+}
+
+fn limit_exceeded() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "decompressed request body exceeds the configured limits",
+    )
+}
+
+/// Parses a single `Content-Encoding` directive, case-insensitively.
+fn parse_encoding(value: &str) -> Option<Encoding> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "br" => Some(Encoding::Brotli),
+        "deflate" => Some(Encoding::Deflate),
+        "gzip" | "x-gzip" => Some(Encoding::Gzip),
+        "zstd" => Some(Encoding::Zstd),
+        "identity" => Some(Encoding::Identity),
+        _ => None,
+    }
+}
+
+/// Whether a decoder for the `Encoding` was compiled in.
+fn is_supported(encoding: Encoding) -> bool {
+    supported_encodings().contains(&encoding)
+}
+
+/// All encodings which can be decoded, as per the enabled crate features.
+fn supported_encodings() -> &'static [Encoding] {
+    &[
+        #[cfg(feature = "brotli")]
+        Encoding::Brotli,
+        #[cfg(feature = "zstd")]
+        Encoding::Zstd,
+        #[cfg(feature = "gzip")]
+        Encoding::Gzip,
+        #[cfg(feature = "deflate")]
+        Encoding::Deflate,
+    ]
+}
+
+/// A `415 Unsupported Media Type` response, which includes an `Accept-Encoding` header of
+/// the supported encodings as per RFC 7694.
+fn unsupported_media_type() -> Response {
+    let mut res = Response::new(StatusCode::UnsupportedMediaType);
+    let accepts: Vec<String> = supported_encodings()
+        .iter()
+        .map(|encoding| encoding.to_string())
+        .collect();
+    if accepts.is_empty() {
+        res.insert_header(headers::ACCEPT_ENCODING, "identity");
+    } else {
+        res.insert_header(headers::ACCEPT_ENCODING, accepts.join(", "));
+    }
+    res
+}
+
+/// Returns a `Body` made from a decoder chosen from the `Encoding`.
+#[cfg_attr(
+    not(any(
+        feature = "brotli",
+        feature = "deflate",
+        feature = "gzip",
+        feature = "zstd"
+    )),
+    allow(
+        unused_variables,
+        reason = "the encoding is unused without any decoders"
+    )
+)]
+fn get_decoder(body: Body, encoding: &Encoding) -> Body {
+    #[cfg(feature = "brotli")]
+    {
+        if *encoding == Encoding::Brotli {
+            return Body::from_reader(BufReader::new(BrotliDecoder::new(body)), None);
+        }
+    }
+
+    #[cfg(feature = "zstd")]
+    {
+        if *encoding == Encoding::Zstd {
+            return Body::from_reader(BufReader::new(ZstdDecoder::new(body)), None);
+        }
+    }
+
+    #[cfg(feature = "gzip")]
+    {
+        if *encoding == Encoding::Gzip {
+            return Body::from_reader(BufReader::new(GzipDecoder::new(body)), None);
+        }
+    }
+
+    #[cfg(feature = "deflate")]
+    {
+        if *encoding == Encoding::Deflate {
+            return Body::from_reader(BufReader::new(DeflateBody::Pending(Some(body))), None);
+        }
+    }
+
+    body
+}
+
+/// Decodes the `deflate` encoding, which is zlib-wrapped as per RFC 9110, but a raw deflate stream from some legacy clients.
+///
+/// The format is told apart by the zlib header, once the first bytes arrive.
+#[cfg(feature = "deflate")]
+enum DeflateBody {
+    Pending(Option<Body>),
+    Zlib(ZlibDecoder<Body>),
+    Raw(DeflateDecoder<Body>),
+}
+
+#[cfg(feature = "deflate")]
+impl AsyncRead for DeflateBody {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        loop {
+            match &mut *self {
+                DeflateBody::Pending(body) => {
+                    let pending = body.as_mut().expect("the body is only taken once decided");
+                    let zlib = is_zlib_header(ready!(Pin::new(pending).poll_fill_buf(cx))?);
+                    let body = body.take().expect("the body is only taken once decided");
+                    *self = if zlib {
+                        DeflateBody::Zlib(ZlibDecoder::new(body))
+                    } else {
+                        DeflateBody::Raw(DeflateDecoder::new(body))
+                    };
+                }
+                DeflateBody::Zlib(decoder) => return Pin::new(decoder).poll_read(cx, buf),
+                DeflateBody::Raw(decoder) => return Pin::new(decoder).poll_read(cx, buf),
+            }
+        }
+    }
+}
+
+/// Whether the bytes start with a zlib header ([RFC 1950][]): deflate with a window of at most 32K, and a valid check.
+///
+/// Too few bytes to tell are taken to be zlib, the standard format.
+///
+/// [RFC 1950]: https://www.rfc-editor.org/rfc/rfc1950
+#[cfg(feature = "deflate")]
+fn is_zlib_header(bytes: &[u8]) -> bool {
+    match bytes {
+        [cmf, flg, ..] => {
+            cmf & 0x0f == 8 && cmf >> 4 <= 7 && u16::from_be_bytes([*cmf, *flg]) % 31 == 0
+        }
+        _ => true,
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Used to create a new DecompressMiddleware with custom settings.
+///
+/// Uses the defaults:
+/// - Maximum decompressed body size (10 MiB).
+/// - No maximum expansion ratio.
+///
+/// ## Example
+/// ```rust
+/// # async_std::task::block_on(async {
+/// let mut app = tide::new();
+///
+/// let decompress_middleware = tide_compress::DecompressMiddleware::builder()
+///     .max_size(Some(1024 * 1024))
+///     .max_ratio(Some(100))
+///     .build();
+///
+/// app.with(decompress_middleware);
+/// # })
+/// ```
+pub struct DecompressMiddlewareBuilder {
+    /// Maximum decompressed body size in bytes. Default `Some(10 MiB)`.
+    pub max_size: Option<usize>,
+    /// Maximum ratio of decompressed bytes to compressed bytes. Default `None`.
+    pub max_ratio: Option<usize>,
+}
+
+impl Default for DecompressMiddlewareBuilder {
+    fn default() -> Self {
+        Self {
+            max_size: Some(MAX_SIZE),
+            max_ratio: None,
+        }
+    }
+}
+
+impl DecompressMiddlewareBuilder {
+    /// Make a new builder.
+    /// Identical to `DecompressMiddleware::builder()`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum decompressed body size, in bytes.
+    pub fn max_size(mut self, max_size: Option<usize>) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Sets the maximum ratio of decompressed bytes to compressed bytes.
+    pub fn max_ratio(mut self, max_ratio: Option<usize>) -> Self {
+        self.max_ratio = max_ratio;
+        self
+    }
+
+    /// Construct a middleware instance from this builder.
+    pub fn build(self) -> DecompressMiddleware {
+        self.into()
+    }
+}
+
+impl From<DecompressMiddlewareBuilder> for DecompressMiddleware {
+    fn from(builder: DecompressMiddlewareBuilder) -> Self {
+        Self {
+            max_size: builder.max_size,
+            max_ratio: builder.max_ratio,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+/// The format of the `deflate` content coding, see [`CompressMiddlewareBuilder::deflate_format`](crate::CompressMiddlewareBuilder::deflate_format).
+///
+/// HTTP defines `deflate` as the zlib format ([RFC 1950][]) wrapping a deflate stream,
+/// but some legacy clients only handle the bare deflate stream ([RFC 1951][]).
+///
+/// [RFC 1950]: https://www.rfc-editor.org/rfc/rfc1950
+/// [RFC 1951]: https://www.rfc-editor.org/rfc/rfc1951
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DeflateFormat {
+    /// zlib-wrapped, as per RFC 9110. The default.
+    #[default]
+    Zlib,
+    /// A raw deflate stream, without the zlib header and checksum.
+    Raw,
+}
@@ -0,0 +1,171 @@
+use tide::http::conditional::ETag;
+use tide::http::content::Encoding;
+use tide::http::{headers, StatusCode};
+use tide::{Request, Response};
+
+/// Encodings which may appear as an `ETag` suffix, see [`ETagMode::Suffix`].
+const SUFFIXED: &[Encoding] = &[
+    Encoding::Brotli,
+    Encoding::Zstd,
+    Encoding::Gzip,
+    Encoding::Deflate,
+];
+
+/// How a response's `ETag` is changed when its body is compressed.
+///
+/// A compressed body is a different representation from the uncompressed one, and so as per
+/// [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110.html#name-etag) must not share the same strong validator.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ETagMode {
+    /// Leave the `ETag` untouched.
+    Keep,
+    /// Turn a strong `ETag` into a weak one, e.g. `"abc"` into `W/"abc"`. The default.
+    #[default]
+    Weaken,
+    /// Suffix the `ETag` with the encoding, e.g. `"abc"` into `"abc-br"`.
+    ///
+    /// Suffixes are removed from incoming `If-None-Match` and `If-Match` headers so that downstream handlers
+    /// can match against their own `ETag`s, and are restored on `304 Not Modified` responses.
+    Suffix,
+}
+
+/// `ETag` suffixes removed from an incoming request, to be restored on a `304 Not Modified` response.
+#[derive(Debug, Default)]
+pub(crate) struct StrippedSuffixes {
+    tags: Vec<(String, Encoding)>,
+}
+
+impl StrippedSuffixes {
+    /// Removes encoding suffixes from the `If-None-Match` and `If-Match` headers of a request.
+    pub(crate) fn strip<State>(req: &mut Request<State>) -> Self {
+        let mut stripped = Self::default();
+        for name in [headers::IF_NONE_MATCH, headers::IF_MATCH] {
+            let value = match req.header(&name) {
+                Some(values) => values
+                    .iter()
+                    .map(|value| value.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                None => continue,
+            };
+
+            // A malformed header is left for the downstream handler to deal with.
+            let tags = match parse_list(&value) {
+                Some(tags) => tags,
+                None => continue,
+            };
+
+            let count = stripped.tags.len();
+            let tags: Vec<String> = tags
+                .into_iter()
+                .map(|tag| match tag {
+                    Some(tag) => match strip_suffix(&tag) {
+                        Some((base, encoding)) => {
+                            stripped.tags.push((tag_value(&base).to_owned(), encoding));
+                            base.to_string()
+                        }
+                        None => tag.to_string(),
+                    },
+                    None => "*".to_owned(),
+                })
+                .collect();
+
+            if stripped.tags.len() > count {
+                req.insert_header(name, tags.join(", "));
+            }
+        }
+        stripped
+    }
+
+    /// Restores the suffix of a `304 Not Modified` response's `ETag`, if it was stripped from the request.
+    pub(crate) fn restore(&self, res: &mut Response) {
+        if res.status() != StatusCode::NotModified || self.tags.is_empty() {
+            return;
+        }
+        if let Ok(Some(etag)) = ETag::from_headers(&*res) {
+            if let Some((_, encoding)) = self
+                .tags
+                .iter()
+                .find(|(value, _)| value == tag_value(&etag))
+            {
+                suffix(&etag, *encoding).apply(res);
+            }
+        }
+    }
+}
+
+/// Changes the `ETag` of a response which has been compressed with the `Encoding`.
+pub(crate) fn rewrite(res: &mut Response, mode: ETagMode, encoding: Encoding) {
+    // An `ETag` which cannot be parsed is left alone rather than failing the response.
+    let etag = match ETag::from_headers(&*res) {
+        Ok(Some(etag)) => etag,
+        _ => return,
+    };
+
+    match mode {
+        ETagMode::Keep => {}
+        ETagMode::Weaken => ETag::Weak(tag_value(&etag).to_owned()).apply(res),
+        ETagMode::Suffix => suffix(&etag, encoding).apply(res),
+    }
+}
+
+fn tag_value(etag: &ETag) -> &str {
+    match etag {
+        ETag::Strong(value) | ETag::Weak(value) => value,
+    }
+}
+
+fn suffix(etag: &ETag, encoding: Encoding) -> ETag {
+    match etag {
+        ETag::Strong(value) => ETag::Strong(format!("{}-{}", value, encoding)),
+        ETag::Weak(value) => ETag::Weak(format!("{}-{}", value, encoding)),
+    }
+}
+
+fn strip_suffix(etag: &ETag) -> Option<(ETag, Encoding)> {
+    SUFFIXED.iter().find_map(|encoding| {
+        let suffix = format!("-{}", encoding);
+        let base = tag_value(etag).strip_suffix(&suffix)?.to_owned();
+        let base = match etag {
+            ETag::Strong(_) => ETag::Strong(base),
+            ETag::Weak(_) => ETag::Weak(base),
+        };
+        Some((base, *encoding))
+    })
+}
+
+/// Parses a comma separated list of entity tags, where `None` is the `*` wildcard.
+///
+/// Entity tags may themselves contain commas, so a plain `split(',')` will not do.
+/// Returns `None` if the list is malformed.
+fn parse_list(value: &str) -> Option<Vec<Option<ETag>>> {
+    let mut tags = vec![];
+    let mut rest = value;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+        if rest.is_empty() {
+            break;
+        }
+        if let Some(after) = rest.strip_prefix('*') {
+            tags.push(None);
+            rest = after;
+            continue;
+        }
+
+        let (weak, quoted) = match rest.strip_prefix("W/") {
+            Some(quoted) => (true, quoted),
+            None => (false, rest),
+        };
+        let opaque = quoted.strip_prefix('"')?;
+        let end = opaque.find('"')?;
+
+        let value = opaque[..end].to_owned();
+        tags.push(Some(if weak {
+            ETag::Weak(value)
+        } else {
+            ETag::Strong(value)
+        }));
+        rest = &opaque[end + 1..];
+    }
+    Some(tags)
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+#[cfg(any(
+    feature = "brotli",
+    feature = "deflate",
+    feature = "gzip",
+    feature = "zstd"
+))]
+mod encoder;
+
+#[cfg(any(
+    feature = "brotli",
+    feature = "deflate",
+    feature = "gzip",
+    feature = "zstd"
+))]
+pub(crate) use encoder::get_flushing_encoder;
+#[cfg(feature = "brotli")]
+pub(crate) use encoder::{Compressor, FlushingEncoder};
+
+/// When the compressor of a streaming (`text/event-stream`) response is flushed.
+///
+/// Compressors buffer their input until a block fills up, which would hold back events from the client.
+/// Flushing emits everything written so far, at a small cost to the compression ratio.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FlushMode {
+    /// Only flush at the end of the body, as with any other response.
+    Never,
+    /// Flush whenever the body has no more data immediately available, i.e. after every chunk. The default.
+    #[default]
+    Immediate,
+    /// Flush once the body has had no more data available for the interval, coalescing bursts of chunks.
+    Idle(Duration),
+}
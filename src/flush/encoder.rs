@@ -0,0 +1,212 @@
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use async_compression::Level;
+use async_io::Timer;
+use futures_lite::io::{AsyncBufRead, AsyncRead, AsyncWrite, BufReader};
+use futures_lite::ready;
+use tide::http::content::{ContentEncoding, Encoding};
+use tide::http::Body;
+
+#[cfg(feature = "brotli")]
+use async_compression::futures::write::BrotliEncoder;
+#[cfg(feature = "gzip")]
+use async_compression::futures::write::GzipEncoder;
+#[cfg(feature = "zstd")]
+use async_compression::futures::write::ZstdEncoder;
+#[cfg(feature = "deflate")]
+use async_compression::futures::write::{DeflateEncoder, ZlibEncoder};
+
+use super::FlushMode;
+#[cfg(feature = "deflate")]
+use crate::DeflateFormat;
+
+/// A compressor which writes into an in-memory buffer.
+pub(crate) trait Compressor: AsyncWrite + Unpin + Send + Sync + 'static {
+    /// The compressed output which has been written so far.
+    fn output(&mut self) -> &mut Vec<u8>;
+}
+
+macro_rules! compressor {
+    ($($feature:literal => $encoder:ident,)*) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl Compressor for $encoder<Vec<u8>> {
+                fn output(&mut self) -> &mut Vec<u8> {
+                    self.get_mut()
+                }
+            }
+        )*
+    };
+}
+
+compressor! {
+    "brotli" => BrotliEncoder,
+    "deflate" => DeflateEncoder,
+    "deflate" => ZlibEncoder,
+    "gzip" => GzipEncoder,
+    "zstd" => ZstdEncoder,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum State {
+    Reading,
+    Closing,
+    Done,
+}
+
+/// Compresses a body, flushing the compressor as per the [`FlushMode`].
+pub(crate) struct FlushingEncoder<C> {
+    body: Body,
+    compressor: C,
+    mode: FlushMode,
+    state: State,
+    /// Whether anything was written since the last flush.
+    unflushed: bool,
+    /// How much of the compressor's output has been read.
+    read: usize,
+    idle: Option<Timer>,
+}
+
+impl<C: Compressor> FlushingEncoder<C> {
+    /// Returns a `Body` which compresses `body` with the compressor.
+    pub(crate) fn body(body: Body, compressor: C, mode: FlushMode) -> Body {
+        Body::from_reader(
+            BufReader::new(Self {
+                body,
+                compressor,
+                mode,
+                state: State::Reading,
+                unflushed: false,
+                read: 0,
+                idle: None,
+            }),
+            None,
+        )
+    }
+
+    /// Whether the compressor should be flushed now that the body has no data available.
+    fn should_flush(&mut self, cx: &mut Context<'_>) -> bool {
+        if !self.unflushed {
+            return false;
+        }
+        match self.mode {
+            FlushMode::Never => false,
+            FlushMode::Immediate => true,
+            FlushMode::Idle(interval) => {
+                let idle = self.idle.get_or_insert_with(|| Timer::after(interval));
+                Pin::new(idle).poll(cx).is_ready()
+            }
+        }
+    }
+}
+
+impl<C: Compressor> AsyncRead for FlushingEncoder<C> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        loop {
+            // Hand out any compressed output first.
+            let output = this.compressor.output();
+            if this.read < output.len() {
+                let len = buf.len().min(output.len() - this.read);
+                buf[..len].copy_from_slice(&output[this.read..this.read + len]);
+                this.read += len;
+                if this.read == output.len() {
+                    output.clear();
+                    this.read = 0;
+                }
+                return Poll::Ready(Ok(len));
+            }
+
+            match this.state {
+                State::Done => return Poll::Ready(Ok(0)),
+                State::Closing => {
+                    ready!(Pin::new(&mut this.compressor).poll_close(cx))?;
+                    this.state = State::Done;
+                }
+                State::Reading => match Pin::new(&mut this.body).poll_fill_buf(cx) {
+                    Poll::Ready(Ok([])) => this.state = State::Closing,
+                    Poll::Ready(Ok(chunk)) => {
+                        let written = ready!(Pin::new(&mut this.compressor).poll_write(cx, chunk))?;
+                        Pin::new(&mut this.body).consume(written);
+                        this.unflushed = true;
+                        this.idle = None;
+                    }
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => {
+                        if !this.should_flush(cx) {
+                            return Poll::Pending;
+                        }
+                        ready!(Pin::new(&mut this.compressor).poll_flush(cx))?;
+                        this.unflushed = false;
+                        this.idle = None;
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Returns a `Body` made from a flushing encoder chosen from the `Encoding`.
+#[allow(
+    clippy::too_many_arguments,
+    reason = "one argument per setting of each compiled in encoder, as with `get_encoder`"
+)]
+pub(crate) fn get_flushing_encoder(
+    body: Body,
+    encoding: &ContentEncoding,
+    mode: FlushMode,
+    #[cfg(feature = "brotli")] brotli_quality: Level,
+    #[cfg(feature = "gzip")] gzip_quality: Level,
+    #[cfg(feature = "deflate")] deflate_quality: Level,
+    #[cfg(feature = "deflate")] deflate_format: DeflateFormat,
+    #[cfg(feature = "zstd")] zstd_quality: Level,
+) -> Body {
+    #[cfg(feature = "brotli")]
+    {
+        if *encoding == Encoding::Brotli {
+            let compressor = BrotliEncoder::with_quality(vec![], brotli_quality);
+            return FlushingEncoder::body(body, compressor, mode);
+        }
+    }
+
+    #[cfg(feature = "zstd")]
+    {
+        if *encoding == Encoding::Zstd {
+            let compressor = ZstdEncoder::with_quality(vec![], zstd_quality);
+            return FlushingEncoder::body(body, compressor, mode);
+        }
+    }
+
+    #[cfg(feature = "gzip")]
+    {
+        if *encoding == Encoding::Gzip {
+            let compressor = GzipEncoder::with_quality(vec![], gzip_quality);
+            return FlushingEncoder::body(body, compressor, mode);
+        }
+    }
+
+    #[cfg(feature = "deflate")]
+    {
+        if *encoding == Encoding::Deflate {
+            return match deflate_format {
+                DeflateFormat::Zlib => {
+                    let compressor = ZlibEncoder::with_quality(vec![], deflate_quality);
+                    FlushingEncoder::body(body, compressor, mode)
+                }
+                DeflateFormat::Raw => {
+                    let compressor = DeflateEncoder::with_quality(vec![], deflate_quality);
+                    FlushingEncoder::body(body, compressor, mode)
+                }
+            };
+        }
+    }
+
+    body
+}
@@ -0,0 +1,125 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_lite::io::{AsyncRead, BufReader};
+use tide::http::Body;
+
+/// Offset of the little-endian modification time in the Gzip header.
+const MTIME: usize = 4;
+/// Offset of the OS byte in the Gzip header.
+const OS: usize = 9;
+/// Length of the Gzip header, without optional fields, which the encoder never writes.
+const HEADER_LEN: usize = 10;
+
+/// Gzip header fields of compressed responses.
+///
+/// The defaults match the encoder's own header, and so do not depend on the host or the time.
+///
+/// ## Example
+/// ```rust
+/// # async_std::task::block_on(async {
+/// use tide_compress::GzipHeader;
+///
+/// let mut app = tide::new();
+///
+/// app.with(
+///     tide_compress::CompressMiddleware::builder()
+///         .gzip_header(GzipHeader::new().mtime(1_700_000_000).os(GzipHeader::UNIX))
+///         .build(),
+/// );
+/// # })
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GzipHeader {
+    /// Modification time, in seconds since the Unix epoch. Default: `0`, meaning no time is available.
+    pub mtime: u32,
+    /// The operating system of the compressing host, as per RFC 1952. Default: `GzipHeader::UNKNOWN_OS`.
+    pub os: u8,
+}
+
+impl GzipHeader {
+    /// The OS byte of a Unix host.
+    pub const UNIX: u8 = 3;
+    /// The OS byte when the operating system is unknown.
+    pub const UNKNOWN_OS: u8 = 255;
+
+    /// Creates the default header fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the modification time, in seconds since the Unix epoch.
+    pub fn mtime(mut self, mtime: u32) -> Self {
+        self.mtime = mtime;
+        self
+    }
+
+    /// Sets the OS byte, as per RFC 1952.
+    pub fn os(mut self, os: u8) -> Self {
+        self.os = os;
+        self
+    }
+
+    /// Whether the fields match the encoder's own header.
+    pub(crate) fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Rewrites the header of a Gzip-compressed body with these fields.
+    pub(crate) fn rewrite(&self, body: Body) -> Body {
+        let mut header = [0; HEADER_LEN];
+        header[MTIME..MTIME + 4].copy_from_slice(&self.mtime.to_le_bytes());
+        header[OS] = self.os;
+        Body::from_reader(
+            BufReader::new(HeaderRewrite {
+                body,
+                header,
+                pos: 0,
+            }),
+            None,
+        )
+    }
+}
+
+impl Default for GzipHeader {
+    fn default() -> Self {
+        Self {
+            mtime: 0,
+            os: Self::UNKNOWN_OS,
+        }
+    }
+}
+
+/// Replaces the modification time & OS bytes as the header passes through.
+struct HeaderRewrite {
+    body: Body,
+    /// The replacement bytes, at their offsets in the header.
+    header: [u8; HEADER_LEN],
+    /// How much of the body has been read, up to the end of the header.
+    pos: usize,
+}
+
+impl AsyncRead for HeaderRewrite {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        let result = Pin::new(&mut this.body).poll_read(cx, buf);
+        if let Poll::Ready(Ok(len)) = result {
+            for (i, byte) in buf[..len].iter_mut().enumerate() {
+                let offset = this.pos + i;
+                if offset >= HEADER_LEN {
+                    break;
+                }
+                if (MTIME..MTIME + 4).contains(&offset) || offset == OS {
+                    *byte = this.header[offset];
+                }
+            }
+            this.pos = (this.pos + len).min(HEADER_LEN);
+        }
+        result
+    }
+}
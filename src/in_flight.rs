@@ -0,0 +1,94 @@
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures_lite::io::{AsyncBufRead, AsyncRead};
+use tide::http::Body;
+
+/// What [`CompressMiddleware`](crate::CompressMiddleware) does with a response once `max_in_flight`
+/// compressed bodies are already in flight.
+///
+/// Either way, the response is sent straight away rather than queued behind the others.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverloadFallback {
+    /// Compress with Gzip at `Level::Fastest`, if the client accepts it, and otherwise send it uncompressed. The default.
+    ///
+    /// Fallback bodies are not counted as in flight.
+    #[default]
+    Cheaper,
+    /// Send the response uncompressed.
+    Identity,
+}
+
+/// Counts the compressed bodies which are currently being produced.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct InFlight {
+    count: Arc<AtomicUsize>,
+}
+
+impl InFlight {
+    /// The number of compressed bodies currently in flight.
+    pub(crate) fn count(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Reserves a slot for a compressed body, unless `max` are already in flight.
+    pub(crate) fn acquire(&self, max: Option<usize>) -> Option<Slot> {
+        self.count
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| match max {
+                Some(max) if count >= max => None,
+                _ => Some(count + 1),
+            })
+            .ok()?;
+        Some(Slot {
+            count: Arc::clone(&self.count),
+        })
+    }
+}
+
+/// A reserved in-flight slot, released once dropped.
+#[derive(Debug)]
+pub(crate) struct Slot {
+    count: Arc<AtomicUsize>,
+}
+
+impl Slot {
+    /// Wraps a compressed body, holding the slot until the body is dropped.
+    pub(crate) fn track(self, body: Body) -> Body {
+        Body::from_reader(TrackedBody { body, _slot: self }, None)
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.count.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+#[derive(Debug)]
+struct TrackedBody {
+    body: Body,
+    _slot: Slot,
+}
+
+impl AsyncRead for TrackedBody {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.body).poll_read(cx, buf)
+    }
+}
+
+impl AsyncBufRead for TrackedBody {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        Pin::new(&mut self.get_mut().body).poll_fill_buf(cx)
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        Pin::new(&mut self.body).consume(amt)
+    }
+}
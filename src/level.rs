@@ -0,0 +1,140 @@
+use std::fmt::Debug;
+
+use async_compression::Level;
+use tide::http::content::Encoding;
+
+/// Picks the compression level per response, rather than one level per encoding for all responses.
+///
+/// A level set by a [`CompressionOverride`](crate::CompressionOverride) takes precedence over the policy.
+///
+/// ## Example
+/// ```rust
+/// # async_std::task::block_on(async {
+/// use async_compression::Level;
+/// use tide::http::content::Encoding;
+/// use tide_compress::{LevelContext, LevelPolicy};
+///
+/// /// Spend more effort on Brotli for bodies of a known, small size.
+/// #[derive(Debug)]
+/// struct SmallBrotli;
+///
+/// impl LevelPolicy for SmallBrotli {
+///     fn level(&self, encoding: Encoding, context: LevelContext) -> Option<Level> {
+///         match (encoding, context.body_len) {
+///             (Encoding::Brotli, Some(len)) if len < 64 * 1024 => Some(Level::Precise(5)),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let mut app = tide::new();
+///
+/// app.with(
+///     tide_compress::CompressMiddleware::builder()
+///         .level_policy(std::sync::Arc::new(SmallBrotli))
+///         .build(),
+/// );
+/// # })
+/// ```
+pub trait LevelPolicy: Debug + Send + Sync + 'static {
+    /// The level to compress a response with, or `None` for the configured quality of the encoding.
+    fn level(&self, encoding: Encoding, context: LevelContext) -> Option<Level>;
+}
+
+/// What is known about a response when picking its compression level, see [`LevelPolicy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LevelContext {
+    /// The uncompressed body length, if known.
+    pub body_len: Option<usize>,
+    /// The number of other responses currently being compressed by the middleware.
+    pub in_flight: usize,
+}
+
+impl LevelContext {
+    pub(crate) fn new(body_len: Option<usize>, in_flight: usize) -> Self {
+        Self {
+            body_len,
+            in_flight,
+        }
+    }
+}
+
+/// A [`LevelPolicy`] picking levels from the body length, and from the number of in-flight compressions.
+///
+/// ## Example
+/// ```rust
+/// # async_std::task::block_on(async {
+/// use async_compression::Level;
+///
+/// let mut app = tide::new();
+///
+/// let policy = tide_compress::SizeLevelPolicy::new()
+///     // Small bodies are cheap to compress well.
+///     .size(16 * 1024, Level::Best)
+///     .size(1024 * 1024, Level::Default)
+///     // Anything larger, or of an unknown length, uses the fastest level.
+///     .fallback(Level::Fastest)
+///     // Under load, always use the fastest level.
+///     .busy(64, Level::Fastest);
+///
+/// app.with(
+///     tide_compress::CompressMiddleware::builder()
+///         .level_policy(std::sync::Arc::new(policy))
+///         .build(),
+/// );
+/// # })
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SizeLevelPolicy {
+    sizes: Vec<(usize, Level)>,
+    fallback: Option<Level>,
+    busy: Option<(usize, Level)>,
+}
+
+impl SizeLevelPolicy {
+    /// Make a new policy, which uses the configured quality of each encoding until configured.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bodies of at most `max_len` bytes are compressed at the level.
+    ///
+    /// Sizes are checked in the order they are added.
+    pub fn size(mut self, max_len: usize, level: Level) -> Self {
+        self.sizes.push((max_len, level));
+        self
+    }
+
+    /// Bodies which match no size, or are of an unknown length, are compressed at the level.
+    pub fn fallback(mut self, level: Level) -> Self {
+        self.fallback = Some(level);
+        self
+    }
+
+    /// Once at least `in_flight` other responses are being compressed, all are compressed at the level.
+    pub fn busy(mut self, in_flight: usize, level: Level) -> Self {
+        self.busy = Some((in_flight, level));
+        self
+    }
+}
+
+impl LevelPolicy for SizeLevelPolicy {
+    fn level(&self, _encoding: Encoding, context: LevelContext) -> Option<Level> {
+        if let Some((in_flight, level)) = self.busy {
+            if context.in_flight >= in_flight {
+                return Some(level);
+            }
+        }
+
+        context
+            .body_len
+            .and_then(|body_len| {
+                self.sizes
+                    .iter()
+                    .find(|(max_len, _)| body_len <= *max_len)
+                    .map(|(_, level)| *level)
+            })
+            .or(self.fallback)
+    }
+}
@@ -61,22 +61,70 @@
 //!
 //! ## Features
 //!
-//! - Support for [Brotli][], [Gzip][], and [Deflate][] encodings, compile-time configurable through cargo feature flags.
-//!   - Prioritizes Brotli if available.
+//! - Support for [Brotli][], [Zstandard][], [Gzip][], and [Deflate][] encodings, compile-time configurable through cargo feature flags.
+//!   - Prioritizes Brotli if available, the preference order & enabled encodings are configurable at runtime.
 //!   - Only pulls in the necessary dependencies for the desired configuration.
 //!   - Defaults to Brotli & Gzip.
 //!   - Also handles the `"identity"` encoding directive [as per RFC 9110][Identity].
-//! - [`Accept-Encoding`][] header checking including priority.
+//!   - Brotli window size (LGWIN), mode (optionally chosen from the `Content-Type`), and size hint are configurable through `BrotliParams`.
+//!   - Compression quality is configurable per encoding, as are the Gzip header modification time & OS byte, through `GzipHeader`.
+//!   - Sends `deflate` in the zlib format [as per RFC 9110][Deflate coding], or optionally as raw deflate for legacy clients.
+//! - [`Accept-Encoding`][] header checking including priority, with the server's preference breaking ties.
+//! - Optional `LevelPolicy` picking the compression level per response, e.g. from the body size or the number of in-flight compressions.
+//! - Optional cap on concurrent compressions (`max_in_flight`), past which responses fall back to fast Gzip or no compression rather than queue.
 //! - Minimum body size threshold (Default: 1024 bytes, configurable).
+//! - Optional `should_compress` predicate over the request method, path, & headers and the response, for custom rules.
 //! - Does not compress responses with a [`Cache-Control: no-transform`][] header.
-//! - Sets the [`Vary`][] header.
+//! - Never compresses informational (1xx), `204 No Content`, or `304 Not Modified` responses, and optionally skips error responses.
+//! - Does not compress range (`206 Partial Content`) responses, and removes `Accept-Ranges` from compressed responses.
+//! - Flushes the compressor of streaming `text/event-stream` (Server-Sent Events) responses after every chunk, or after an idle interval.
+//! - Sets the [`Vary`][] header, merging with any existing entries and leaving `Vary: *` alone, and optionally only when the response depends on the request.
+//! - Optional `CompressionObserver` for metrics: compressed & skipped (with reason) responses, and bytes in & out and time spent per encoder.
+//! - Opt-in debugging of the compression decision, as a `CompressionDecision` response extension and optionally an `X-Compression-Decision` header.
+//! - Weakens, or optionally suffixes, the strong `ETag` of compressed responses [as per RFC 9110][ETag].
+//! - Optional bounded LRU cache of compressed bodies, keyed by path & query, strong `ETag`, and encoding.
+//! - Per-route overrides of whether to compress, the level, and the allowed encodings, through `CompressionOverride` response extensions, or request extensions set by earlier app-level middleware.
 //! - Checks the [`Content-Type`][] header (MIME).
+//!   - User-supplied allow & deny entries, exact or patterns such as `application/vnd.ourco.*`, take precedence over the database and the regular expression.
 //!   - Checks against [jshttp's comprehensive database][jshttp mime-db], which is compiled to a [perfect hash function][].
-//!   - The database can be regenerated in the crate's repository by running `cargo run generate-database`.
-//!   - If not in the database, checks against a regular expression.
+//!   - Types the database knows to be incompressible, such as `image/png` or `application/zip`, are rejected outright. It also knows which are text or fonts, for `BrotliMode::FromContentType`.
+//!   - The database can be regenerated in the crate's repository by running `cargo run -p generate-database`, which fetches `db.json`, or offline with `cargo run -p generate-database -- path/to/db.json`. The `db.json` used is kept as `generate-database/db.json`, and used on later runs. Run `cargo run -p generate-database -- --fetch` to update it from upstream.
+//!   - If not in the database, checks against a regular expression, and optionally `ContentTypeMatcher` prefix, suffix & exact rules.
 //!     - Default: `^text/|\+(?:json|text|xml)$` (case insensitive).
 //!     - Fully override-able to any custom [`Regex`][], with `None` as an option.
-//!   - Functionality can be excluded in crate features if the `regex` crate poses build issues.
+//!   - Responses without a `Content-Type` are compressed, or optionally skipped, or sniffed for the signatures of binary formats such as PNG, JPEG, ZIP, or Gzip. When skipping or sniffing, so are responses labelled `application/octet-stream`, tide's default for bodies without a MIME type.
+//!   - Functionality can be excluded in crate features if the `regex` crate poses build issues. Without `regex-check`, `ContentTypeMatcher` rules can take the place of the regular expression, and `db-check` works standalone.
+//!
+//! ## Precompressed Static Files
+//!
+//! [`ServeDirCompressed`] serves a directory of static files, preferring precompressed `.br`, `.zst`, or `.gz` siblings
+//! as negotiated from `Accept-Encoding`, and falling back to the original file otherwise.
+//!
+//! ```rust,no_run
+//! # fn main() -> std::io::Result<()> {
+//! let mut app = tide::new();
+//! app.with(tide_compress::CompressMiddleware::new());
+//! app.at("/static/*")
+//!     .get(tide_compress::ServeDirCompressed::new("/static/", "public/")?);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Request Decompression
+//!
+//! A companion [`DecompressMiddleware`] decodes incoming request bodies which have a `Content-Encoding` header,
+//! using the same cargo features as the encoders.
+//! Requests with an unsupported encoding are rejected with `415 Unsupported Media Type`.
+//! Decompressed bodies are limited in size (Default: 10 MiB) and optionally in expansion ratio to guard against
+//! decompression bombs, answering with `413 Payload Too Large` once exceeded.
+//!
+//! ```rust
+//! #[async_std::main]
+//! async fn main() {
+//!     let mut app = tide::new();
+//!     app.with(tide_compress::DecompressMiddleware::new());
+//! }
+//! ```
 //!
 //! [`Accept-Encoding`]: https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Accept-Encoding
 //! [`Cache-Control: no-transform`]: https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Cache-Control
@@ -88,12 +136,78 @@
 //! [Brotli]: https://en.wikipedia.org/wiki/Brotli
 //! [Deflate]: https://en.wikipedia.org/wiki/Deflate
 //! [Gzip]: https://en.wikipedia.org/wiki/Gzip
+//! [Zstandard]: https://en.wikipedia.org/wiki/Zstd
+//! [ETag]: https://www.rfc-editor.org/rfc/rfc9110.html#name-etag
 //! [Identity]: https://www.rfc-editor.org/rfc/rfc9110.html#name-accept-encoding
+//! [Deflate coding]: https://www.rfc-editor.org/rfc/rfc9110.html#name-deflate-coding
 //! [Tide]: https://github.com/http-rs/tide
 
 #[cfg(feature = "db-check")]
+// Generated, see the `generate-database` directory.
 mod codegen_database;
+#[cfg(feature = "db-check")]
+mod mime_db;
 
+#[cfg(feature = "brotli")]
+mod brotli_params;
+mod cache;
+mod content_type_list;
+mod content_type_matcher;
+#[cfg(any(feature = "brotli", feature = "db-check"))]
+#[cfg_attr(
+    not(feature = "brotli"),
+    allow(dead_code, reason = "only tunes Brotli")
+)]
+mod data_kind;
+mod decision;
+mod decompress;
+#[cfg(feature = "deflate")]
+mod deflate_format;
+mod etag;
+mod flush;
+#[cfg(feature = "gzip")]
+mod gzip_header;
+mod in_flight;
+#[cfg(any(
+    feature = "brotli",
+    feature = "deflate",
+    feature = "gzip",
+    feature = "zstd"
+))]
+mod level;
 mod middleware;
+mod negotiate;
+mod observer;
+mod overrides;
+mod precompressed;
+mod predicate;
+mod sniff;
 
+#[cfg(feature = "brotli")]
+pub use brotli_params::{BrotliMode, BrotliParams};
+pub use cache::CompressionCache;
+pub use content_type_matcher::ContentTypeMatcher;
+pub use decision::{
+    CompressionDecision, CompressionOutcome, ContentTypeRule, DebugDecision, DECISION_HEADER,
+};
+pub use decompress::{DecompressMiddleware, DecompressMiddlewareBuilder};
+#[cfg(feature = "deflate")]
+pub use deflate_format::DeflateFormat;
+pub use etag::ETagMode;
+pub use flush::FlushMode;
+#[cfg(feature = "gzip")]
+pub use gzip_header::GzipHeader;
+pub use in_flight::OverloadFallback;
+#[cfg(any(
+    feature = "brotli",
+    feature = "deflate",
+    feature = "gzip",
+    feature = "zstd"
+))]
+pub use level::{LevelContext, LevelPolicy, SizeLevelPolicy};
 pub use middleware::{CompressMiddleware, CompressMiddlewareBuilder};
+pub use observer::{CompressionObserver, EncoderStats, SkipReason};
+pub use overrides::CompressionOverride;
+pub use precompressed::ServeDirCompressed;
+pub use predicate::{RequestParts, ShouldCompress};
+pub use sniff::MissingContentType;
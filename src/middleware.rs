@@ -1,35 +1,93 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use futures_lite::io::{AsyncReadExt, Cursor};
 use tide::http::cache::{CacheControl, CacheDirective};
-use tide::http::conditional::Vary;
-use tide::http::content::{AcceptEncoding, ContentEncoding, Encoding};
-use tide::http::{headers, Body, Method};
+use tide::http::conditional::{ETag, Vary};
+use tide::http::content::{ContentEncoding, Encoding};
+use tide::http::{headers, mime, Body, Method, Mime, StatusCode};
 use tide::{Middleware, Next, Request, Response};
 
-#[cfg(any(feature = "brotli", feature = "deflate", feature = "gzip"))]
+use crate::content_type_list::ContentTypeLists;
+use crate::decision::DECISION_HEADER;
+use crate::etag::{self, StrippedSuffixes};
+use crate::in_flight::{InFlight, Slot};
+use crate::negotiate::{negotiate, Accepts};
+use crate::observer::observe;
+use crate::predicate::RequestParts;
+use crate::sniff;
+use crate::{
+    CompressionCache, CompressionDecision, CompressionObserver, CompressionOutcome,
+    CompressionOverride, ContentTypeMatcher, ContentTypeRule, DebugDecision, ETagMode, FlushMode,
+    MissingContentType, OverloadFallback, ShouldCompress, SkipReason,
+};
+
+#[cfg(any(
+    feature = "brotli",
+    feature = "deflate",
+    feature = "gzip",
+    feature = "zstd"
+))]
+use crate::{LevelContext, LevelPolicy};
+#[cfg(any(
+    feature = "brotli",
+    feature = "deflate",
+    feature = "gzip",
+    feature = "zstd"
+))]
 use async_compression::Level;
-#[cfg(any(feature = "brotli", feature = "deflate", feature = "gzip"))]
+#[cfg(any(
+    feature = "brotli",
+    feature = "deflate",
+    feature = "gzip",
+    feature = "zstd"
+))]
 use futures_lite::io::BufReader;
 
 #[cfg(feature = "brotli")]
 use async_compression::futures::bufread::BrotliEncoder;
-#[cfg(feature = "deflate")]
-use async_compression::futures::bufread::DeflateEncoder;
 #[cfg(feature = "gzip")]
 use async_compression::futures::bufread::GzipEncoder;
+#[cfg(feature = "zstd")]
+use async_compression::futures::bufread::ZstdEncoder;
+#[cfg(feature = "deflate")]
+use async_compression::futures::bufread::{DeflateEncoder, ZlibEncoder};
 
-#[cfg(feature = "regex-check")]
-use http_types::content::ContentType;
+#[cfg(feature = "brotli")]
+use crate::brotli_params::BrotliCompressor;
+#[cfg(feature = "brotli")]
+use crate::flush::FlushingEncoder;
+#[cfg(feature = "brotli")]
+use crate::BrotliParams;
+#[cfg(feature = "deflate")]
+use crate::DeflateFormat;
+#[cfg(feature = "gzip")]
+use crate::GzipHeader;
+
+#[cfg(feature = "db-check")]
+use crate::mime_db::{self, Compressibility};
 #[cfg(feature = "regex-check")]
 use regex::{Regex, RegexBuilder};
 
 const THRESHOLD: usize = 1024;
 
+/// Encodings which can be negotiated, in the default order of preference.
+const ENCODINGS: &[Encoding] = &[
+    #[cfg(feature = "brotli")]
+    Encoding::Brotli,
+    #[cfg(feature = "zstd")]
+    Encoding::Zstd,
+    #[cfg(feature = "gzip")]
+    Encoding::Gzip,
+    #[cfg(feature = "deflate")]
+    Encoding::Deflate,
+];
+
 // These regular expressions ere taken from jshttp/compressible
 // Used under terms of the MIT license.
 // https://github.com/jshttp/compressible/blob/89b61014fb82f0c64b42acef12d161dee48fb58e/index.js#L23-L24
 #[cfg(feature = "regex-check")]
 const CONTENT_TYPE_CHECK_PATTERN: &str = r"^text/|\+(?:json|text|xml)$";
-#[cfg(feature = "regex-check")]
-const EXTRACT_TYPE_PATTERN: &str = r"^\s*([^;\s]*)(?:;|\s|$)";
 
 /// A middleware for compressing response body data.
 ///
@@ -46,12 +104,43 @@ pub struct CompressMiddleware {
     threshold: usize,
     #[cfg(feature = "regex-check")]
     content_type_check: Option<Regex>,
-    #[cfg(feature = "regex-check")]
-    extract_type_regex: Regex,
+    content_type_matcher: Option<ContentTypeMatcher>,
+    content_type_lists: ContentTypeLists,
+    missing_content_type: MissingContentType,
     #[cfg(feature = "brotli")]
     brotli_quality: Level,
-    #[cfg(any(feature = "gzip", feature = "deflate"))]
+    #[cfg(feature = "brotli")]
+    brotli_params: BrotliParams,
+    #[cfg(feature = "gzip")]
+    gzip_quality: Level,
+    #[cfg(feature = "gzip")]
+    gzip_header: GzipHeader,
+    #[cfg(feature = "deflate")]
     deflate_quality: Level,
+    #[cfg(feature = "deflate")]
+    deflate_format: DeflateFormat,
+    #[cfg(feature = "zstd")]
+    zstd_quality: Level,
+    cache: Option<CompressionCache>,
+    etag_mode: ETagMode,
+    strip_accept_ranges: bool,
+    compress_errors: bool,
+    vary_on_skip: bool,
+    should_compress: Option<ShouldCompress>,
+    encodings: Vec<Encoding>,
+    streaming_flush: FlushMode,
+    observer: Option<Arc<dyn CompressionObserver>>,
+    debug_decision: DebugDecision,
+    #[cfg(any(
+        feature = "brotli",
+        feature = "deflate",
+        feature = "gzip",
+        feature = "zstd"
+    ))]
+    level_policy: Option<Arc<dyn LevelPolicy>>,
+    max_in_flight: Option<usize>,
+    overload_fallback: OverloadFallback,
+    in_flight: InFlight,
 }
 
 impl Default for CompressMiddleware {
@@ -107,25 +196,343 @@ impl CompressMiddleware {
     pub fn content_type_check(&self) -> Option<&Regex> {
         self.content_type_check.as_ref()
     }
+
+    /// Sets the `Content-Type` header (MIME) check rules.
+    pub fn set_content_type_matcher(&mut self, content_type_matcher: Option<ContentTypeMatcher>) {
+        self.content_type_matcher = content_type_matcher
+    }
+
+    /// Gets a reference to the existing `Content-Type` header (MIME) check rules.
+    pub fn content_type_matcher(&self) -> Option<&ContentTypeMatcher> {
+        self.content_type_matcher.as_ref()
+    }
+
+    /// Sets the compressed body cache.
+    pub fn set_cache(&mut self, cache: Option<CompressionCache>) {
+        self.cache = cache
+    }
+
+    /// Gets a reference to the existing compressed body cache.
+    pub fn cache(&self) -> Option<&CompressionCache> {
+        self.cache.as_ref()
+    }
+
+    /// Sets the predicate deciding whether a response may be compressed.
+    pub fn set_should_compress(&mut self, should_compress: Option<ShouldCompress>) {
+        self.should_compress = should_compress
+    }
+
+    /// Gets a reference to the existing predicate deciding whether a response may be compressed.
+    pub fn should_compress(&self) -> Option<&ShouldCompress> {
+        self.should_compress.as_ref()
+    }
+
+    /// Sets the enabled encodings, in order of preference.
+    ///
+    /// Encodings which are not compiled in are ignored.
+    pub fn set_encodings(&mut self, encodings: impl Into<Vec<Encoding>>) {
+        self.encodings = compiled_in(encodings.into())
+    }
+
+    /// Gets the existing enabled encodings, in order of preference.
+    pub fn encodings(&self) -> &[Encoding] {
+        &self.encodings
+    }
+
+    /// Gets the number of compressed bodies currently in flight.
+    ///
+    /// Only counted when something depends on it, such as a level policy or `max_in_flight`, and otherwise always `0`.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.count()
+    }
+
+    /// Reports a skipped response, returning the response otherwise unchanged.
+    fn skip(&self, reason: SkipReason, mut res: Response) -> tide::Result {
+        self.report(CompressionOutcome::Skipped(reason), None, &mut res);
+        Ok(res)
+    }
+
+    /// Reports the outcome to the observer, and attaches the decision to the response if debugging.
+    fn report(
+        &self,
+        outcome: CompressionOutcome,
+        content_type_rule: Option<ContentTypeRule>,
+        res: &mut Response,
+    ) {
+        if let Some(observer) = &self.observer {
+            match outcome {
+                CompressionOutcome::Compressed(encoding) => observer.compressed(encoding),
+                CompressionOutcome::Skipped(reason) => observer.skipped(reason),
+            }
+        }
+
+        if self.debug_decision == DebugDecision::Off {
+            return;
+        }
+        let decision = CompressionDecision {
+            outcome,
+            threshold: self.threshold,
+            content_type_rule,
+        };
+        if self.debug_decision == DebugDecision::Header {
+            res.insert_header(DECISION_HEADER, decision.to_string());
+        }
+        res.insert_ext(decision);
+    }
+
+    /// Checks whether the `Content-Type` header indicates a compressible body.
+    ///
+    /// In order: the user-supplied deny & allow entries, the MIME database, then the regex & the matcher.
+    /// Returns `None` if there is nothing to check.
+    /// A `Content-Type` which does not parse matches nothing, rather than failing the response.
+    fn content_type_rule(&self, res: &Response) -> Option<ContentTypeRule> {
+        let mime = match Mime::from_str(res.header(headers::CONTENT_TYPE)?.last().as_str()) {
+            Ok(mime) => mime,
+            Err(_) if self.checks_content_type() => return Some(ContentTypeRule::Unmatched),
+            Err(_) => return None,
+        };
+        let essence = mime.essence();
+
+        if let Some(rule) = self.content_type_lists.rule(essence) {
+            return Some(rule);
+        }
+
+        // Turning off `content_type_check` turns off the MIME database too, unless there is a matcher.
+        #[cfg(feature = "regex-check")]
+        if !self.checks_content_type() {
+            return None;
+        }
+
+        // Known types are decided by the MIME database, without consulting the regex or the matcher.
+        #[cfg(feature = "db-check")]
+        match mime_db::compressibility(essence) {
+            Compressibility::Compressible => return Some(ContentTypeRule::Database),
+            Compressibility::Incompressible => return Some(ContentTypeRule::Incompressible),
+            Compressibility::Unknown => {}
+        }
+
+        if !self.checks_content_type() {
+            return None;
+        }
+        #[cfg(feature = "regex-check")]
+        if let Some(content_type_check) = &self.content_type_check {
+            if content_type_check.is_match(essence) {
+                return Some(ContentTypeRule::Regex);
+            }
+        }
+        if let Some(content_type_matcher) = &self.content_type_matcher {
+            if content_type_matcher.is_match(essence) {
+                return Some(ContentTypeRule::Matcher);
+            }
+        }
+        Some(ContentTypeRule::Unmatched)
+    }
+
+    /// Returns a `Body` backed by an appropriate encoder, if one is available,
+    /// reporting to the observer and counting it as in flight as necessary.
+    fn encode_observed(
+        &self,
+        body: Body,
+        encoding: &ContentEncoding,
+        overrides: Option<&CompressionOverride>,
+        flush: FlushMode,
+        slot: Option<Slot>,
+        #[cfg(feature = "brotli")] content_type: Option<&Mime>,
+    ) -> Body {
+        // The slot of this body is already counted.
+        let others = self
+            .in_flight
+            .count()
+            .saturating_sub(usize::from(slot.is_some()));
+        let encode = |body| {
+            let body = self.encode(
+                body,
+                encoding,
+                overrides,
+                flush,
+                others,
+                #[cfg(feature = "brotli")]
+                content_type,
+            );
+            #[cfg(feature = "gzip")]
+            if **encoding == Encoding::Gzip && !self.gzip_header.is_default() {
+                return self.gzip_header.rewrite(body);
+            }
+            body
+        };
+        let body = match &self.observer {
+            Some(observer) => observe(body, **encoding, observer, encode),
+            None => encode(body),
+        };
+        match slot {
+            Some(slot) => slot.track(body),
+            None => body,
+        }
+    }
+
+    /// Whether the `Content-Type` is checked beyond the allow & deny entries.
+    fn checks_content_type(&self) -> bool {
+        #[cfg(feature = "regex-check")]
+        return self.content_type_check.is_some() || self.content_type_matcher.is_some();
+        #[cfg(not(feature = "regex-check"))]
+        return self.content_type_matcher.is_some();
+    }
+
+    /// Whether anything depends on the number of in-flight compressed bodies.
+    fn tracks_in_flight(&self) -> bool {
+        #[cfg(any(
+            feature = "brotli",
+            feature = "deflate",
+            feature = "gzip",
+            feature = "zstd"
+        ))]
+        return self.level_policy.is_some() || self.max_in_flight.is_some();
+        #[cfg(not(any(
+            feature = "brotli",
+            feature = "deflate",
+            feature = "gzip",
+            feature = "zstd"
+        )))]
+        return self.max_in_flight.is_some();
+    }
+
+    /// Returns a `Body` backed by an appropriate encoder, if one is available.
+    #[cfg_attr(
+        not(any(
+            feature = "brotli",
+            feature = "deflate",
+            feature = "gzip",
+            feature = "zstd"
+        )),
+        allow(unused_variables, reason = "nothing is encoded without any encoders")
+    )]
+    fn encode(
+        &self,
+        body: Body,
+        encoding: &ContentEncoding,
+        overrides: Option<&CompressionOverride>,
+        flush: FlushMode,
+        in_flight: usize,
+        #[cfg(feature = "brotli")] content_type: Option<&Mime>,
+    ) -> Body {
+        #[cfg(any(
+            feature = "brotli",
+            feature = "deflate",
+            feature = "gzip",
+            feature = "zstd"
+        ))]
+        let level = overrides.and_then(|overrides| overrides.level).or_else(|| {
+            let policy = self.level_policy.as_ref()?;
+            let context = LevelContext::new(body.len(), in_flight);
+            policy.level(**encoding, context)
+        });
+        #[cfg(feature = "brotli")]
+        if **encoding == Encoding::Brotli && !self.brotli_params.is_default() {
+            let quality = level.unwrap_or(self.brotli_quality);
+            let params = self
+                .brotli_params
+                .encoder_params(quality, content_type, body.len());
+            return FlushingEncoder::body(body, BrotliCompressor::new(&params), flush);
+        }
+        #[cfg(any(
+            feature = "brotli",
+            feature = "deflate",
+            feature = "gzip",
+            feature = "zstd"
+        ))]
+        if flush != FlushMode::Never {
+            return crate::flush::get_flushing_encoder(
+                body,
+                encoding,
+                flush,
+                #[cfg(feature = "brotli")]
+                level.unwrap_or(self.brotli_quality),
+                #[cfg(feature = "gzip")]
+                level.unwrap_or(self.gzip_quality),
+                #[cfg(feature = "deflate")]
+                level.unwrap_or(self.deflate_quality),
+                #[cfg(feature = "deflate")]
+                self.deflate_format,
+                #[cfg(feature = "zstd")]
+                level.unwrap_or(self.zstd_quality),
+            );
+        }
+        get_encoder(
+            body,
+            encoding,
+            #[cfg(feature = "brotli")]
+            level.unwrap_or(self.brotli_quality),
+            #[cfg(feature = "gzip")]
+            level.unwrap_or(self.gzip_quality),
+            #[cfg(feature = "deflate")]
+            level.unwrap_or(self.deflate_quality),
+            #[cfg(feature = "deflate")]
+            self.deflate_format,
+            #[cfg(feature = "zstd")]
+            level.unwrap_or(self.zstd_quality),
+        )
+    }
 }
 
 #[tide::utils::async_trait]
 impl<State: Clone + Send + Sync + 'static> Middleware<State> for CompressMiddleware {
-    async fn handle(&self, req: Request<State>, next: Next<'_, State>) -> tide::Result {
+    async fn handle(&self, mut req: Request<State>, next: Next<'_, State>) -> tide::Result {
         // Incoming Request data
         // Need to grab these things before the request is consumed by `next.run()`.
         let is_head = req.method() == Method::Head;
-        let accepts = AcceptEncoding::from_headers(&req)?;
+        let accepts = Accepts::from_headers(&req)?;
+        let resource = self.cache.as_ref().map(|_| {
+            let url = req.url();
+            match url.query() {
+                Some(query) => format!("{}?{}", url.path(), query),
+                None => url.path().to_owned(),
+            }
+        });
+        let request_overrides = req.ext::<CompressionOverride>().cloned();
+        let request_parts = self
+            .should_compress
+            .as_ref()
+            .map(|_| RequestParts::new(&req));
+        let stripped_suffixes = match self.etag_mode {
+            ETagMode::Suffix => Some(StrippedSuffixes::strip(&mut req)),
+            _ => None,
+        };
 
         // Propagate to route
         let mut res: Response = next.run(req).await;
 
+        if let Some(stripped_suffixes) = stripped_suffixes {
+            stripped_suffixes.restore(&mut res);
+        }
+
+        // Per-route overrides are honored before any other checks.
+        #[cfg_attr(
+            not(feature = "gzip"),
+            allow(unused_mut, reason = "only mutated by the Gzip overload fallback")
+        )]
+        let mut overrides = res
+            .ext::<CompressionOverride>()
+            .cloned()
+            .or(request_overrides);
+        let forced = match overrides.as_ref().and_then(|overrides| overrides.enabled) {
+            Some(false) => return self.skip(SkipReason::Override, res),
+            Some(true) => true,
+            None => false,
+        };
+
         // Head requests should have no body to compress.
-        // Can't tell if we can compress if there is no Accepts-Encoding header.
-        if is_head || accepts.is_none() {
-            return Ok(res);
+        if is_head {
+            return self.skip(SkipReason::Head, res);
         }
-        let mut accepts = accepts.expect("checked directly above");
+        // Can't tell if we can compress if there is no Accepts-Encoding header.
+        let accepts = match accepts {
+            Some(accepts) => accepts,
+            // Depends on the request, so a `Vary` is needed whatever `vary_on_skip` is.
+            None => {
+                merge_vary(&mut res)?;
+                return self.skip(SkipReason::NoAcceptEncoding, res);
+            }
+        };
 
         // Should we transform?
         if let Some(cache_control) = CacheControl::from_headers(&res)? {
@@ -135,101 +542,315 @@ impl<State: Clone + Send + Sync + 'static> Middleware<State> for CompressMiddlew
                 .iter()
                 .any(|directive| directive == &CacheDirective::NoTransform)
             {
-                return Ok(res);
+                return self.skip(SkipReason::NoTransform, res);
             }
         }
 
+        // Range responses are a slice of the unencoded body, compressing them would produce a corrupt partial stream.
+        if res.status() == StatusCode::PartialContent
+            || res.header(headers::CONTENT_RANGE).is_some()
+        {
+            return self.skip(SkipReason::Range, res);
+        }
+
         // Set the Vary header, similar to how https://www.npmjs.com/package/compression does it.
-        let mut vary = Vary::new();
-        vary.push(headers::ACCEPT_ENCODING)?;
-        vary.apply(&mut res);
+        // Otherwise deferred until the outcome depends on the request.
+        if self.vary_on_skip {
+            merge_vary(&mut res)?;
+        }
+
+        // Some responses must not have a body, and so must never have a `Content-Encoding`.
+        let status = res.status();
+        if status.is_informational()
+            || status == StatusCode::NoContent
+            || status == StatusCode::NotModified
+        {
+            return self.skip(SkipReason::Status, res);
+        }
+
+        // Error responses may be excluded.
+        if !self.compress_errors && (status.is_client_error() || status.is_server_error()) {
+            return self.skip(SkipReason::Status, res);
+        }
 
         // Check if an encoding may already exist.
         // Can't tell if we should compress if an encoding set.
         if let Some(previous_encoding) = ContentEncoding::from_headers(&res)? {
             if previous_encoding != Encoding::Identity {
-                return Ok(res);
+                return self.skip(SkipReason::AlreadyEncoded, res);
+            }
+        }
+
+        // Check the user-supplied predicate.
+        if let (false, Some(should_compress), Some(request_parts)) =
+            (forced, &self.should_compress, &request_parts)
+        {
+            if !should_compress.call(request_parts, &res) {
+                if !self.vary_on_skip {
+                    merge_vary(&mut res)?;
+                }
+                return self.skip(SkipReason::Predicate, res);
             }
         }
 
         // Check body length against threshold.
-        if let Some(body_len) = res.len() {
+        if let (false, Some(body_len)) = (forced, res.len()) {
             if body_len < self.threshold {
-                return Ok(res);
+                return self.skip(SkipReason::BelowThreshold, res);
             }
         }
 
-        #[cfg(feature = "regex-check")]
         // Check if the `Content-Type` header indicates a compressible body.
-        if let Some(ref content_type_check) = self.content_type_check {
-            if let Some(content_type) = ContentType::from_headers(&res)? {
-                if let Some(extension_match) = self
-                    .extract_type_regex
-                    .captures(content_type.value().as_str())
-                    .and_then(|captures| captures.get(1))
-                {
-                    #[cfg(feature = "db-check")]
-                    // See `codegen_database.rs` & `generate-database` directory.
-                    // Pulls from a JSON MIME database for compressible entries and puts them
-                    //  into a set with a perfect hash function, with roughly or near to O(1) lookup time.
-                    if !crate::codegen_database::MIME_DB.contains(extension_match.as_str())
-                        && !content_type_check.is_match(extension_match.as_str())
+        let labelled = res.header(headers::CONTENT_TYPE).is_some();
+        // `application/octet-stream` is the default label of bodies set without one, see `MissingContentType`.
+        let missing_content_type = !forced
+            && (!labelled
+                || (self.missing_content_type != MissingContentType::Always
+                    && res.content_type().is_some_and(|content_type| {
+                        content_type.essence() == mime::BYTE_STREAM.essence()
+                    })));
+        let mut content_type_rule = if forced {
+            None
+        } else if missing_content_type {
+            match self.missing_content_type {
+                MissingContentType::Never => Some(ContentTypeRule::Missing),
+                MissingContentType::Always | MissingContentType::Sniff => None,
+            }
+        } else {
+            self.content_type_rule(&res)
+        };
+        if matches!(
+            content_type_rule,
+            Some(
+                ContentTypeRule::Denied
+                    | ContentTypeRule::Incompressible
+                    | ContentTypeRule::Unmatched
+                    | ContentTypeRule::Missing
+            )
+        ) {
+            self.report(
+                CompressionOutcome::Skipped(SkipReason::ContentType),
+                content_type_rule,
+                &mut res,
+            );
+            return Ok(res);
+        }
+
+        if !self.vary_on_skip {
+            merge_vary(&mut res)?;
+        }
+
+        let restricted = overrides.as_ref().and_then(|o| o.encodings.as_ref());
+        let mut available = match restricted {
+            Some(encodings) => compiled_in(encodings.clone()),
+            None => self.encodings.clone(),
+        };
+        let restricted = restricted.is_some();
+
+        // Past the cap on concurrent compressions, fall back straight away rather than queue.
+        let slot = if self.tracks_in_flight() {
+            self.in_flight.acquire(self.max_in_flight)
+        } else {
+            None
+        };
+        let overloaded = self.max_in_flight.is_some() && slot.is_none();
+        if overloaded {
+            if let Some(observer) = &self.observer {
+                observer.overloaded();
+            }
+            match self.overload_fallback {
+                OverloadFallback::Cheaper => {
+                    available.retain(|encoding| *encoding == Encoding::Gzip);
+                    #[cfg(feature = "gzip")]
                     {
-                        return Ok(res);
-                    }
-                    #[cfg(not(feature = "db-check"))]
-                    if !content_type_check.is_match(extension_match.as_str()) {
-                        return Ok(res);
+                        overrides.get_or_insert_with(Default::default).level = Some(Level::Fastest);
                     }
                 }
+                OverloadFallback::Identity => available.clear(),
             }
         }
 
-        let encoding = accepts.negotiate(&[
-            #[cfg(feature = "brotli")]
-            Encoding::Brotli,
-            #[cfg(feature = "gzip")]
-            Encoding::Gzip,
-            #[cfg(feature = "deflate")]
-            Encoding::Deflate,
-            Encoding::Identity, // Prioritize compression when acceptable.
-        ])?;
+        available.push(Encoding::Identity); // Prioritize compression when acceptable.
+        let encoding: ContentEncoding = match negotiate(&accepts, &available) {
+            Some(Encoding::Identity) | None if overloaded => {
+                res.remove_header(headers::CONTENT_ENCODING);
+                let outcome = CompressionOutcome::Skipped(SkipReason::Overloaded);
+                self.report(outcome, content_type_rule, &mut res);
+                return Ok(res);
+            }
+            Some(encoding) => encoding.into(),
+            // The override narrowed the choice, so fall back to an uncompressed body rather than a 406.
+            None if restricted => {
+                let outcome = CompressionOutcome::Skipped(SkipReason::Identity);
+                self.report(outcome, content_type_rule, &mut res);
+                return Ok(res);
+            }
+            // Otherwise identity is acceptable unless the client excluded it.
+            None => {
+                return Err(tide::Error::from_str(
+                    StatusCode::NotAcceptable,
+                    "No suitable Content-Encoding found",
+                ))
+            }
+        };
 
         // Short-circuit case without modifying body.
         if encoding == Encoding::Identity {
             res.remove_header(headers::CONTENT_ENCODING);
+            let outcome = CompressionOutcome::Skipped(SkipReason::Identity);
+            self.report(outcome, content_type_rule, &mut res);
             return Ok(res);
         }
 
-        let body = res.take_body();
-        // Get a new Body backed by an appropriate encoder, if one is available.
-        res.set_body(get_encoder(
-            body,
-            &encoding,
-            #[cfg(feature = "brotli")]
-            self.brotli_quality,
-            #[cfg(any(feature = "gzip", feature = "deflate"))]
-            self.deflate_quality,
-        ));
+        // Sniffed only now, as it waits for the first bytes of the body.
+        if missing_content_type && self.missing_content_type == MissingContentType::Sniff {
+            let mut body = res.take_body();
+            let text = sniff::is_text(&mut body).await?;
+            res.set_body(body);
+            // Setting the body also sets its default `Content-Type`.
+            if !labelled {
+                res.remove_header(headers::CONTENT_TYPE);
+            }
+            if !text {
+                content_type_rule = Some(ContentTypeRule::SniffedBinary);
+                let outcome = CompressionOutcome::Skipped(SkipReason::ContentType);
+                self.report(outcome, content_type_rule, &mut res);
+                return Ok(res);
+            }
+            content_type_rule = Some(ContentTypeRule::SniffedText);
+        }
+
+        // Streaming responses are flushed as they go, rather than buffered by the compressor.
+        let flush = match res.content_type() {
+            Some(mime) if mime.essence() == "text/event-stream" => self.streaming_flush,
+            _ => FlushMode::Never,
+        };
+        #[cfg(feature = "brotli")]
+        let content_type = res.content_type();
+
+        // Only complete responses with a strong validator, compressed with the usual settings, may be cached.
+        let cacheable = res.status() == StatusCode::Ok
+            && flush == FlushMode::Never
+            && !overrides
+                .as_ref()
+                .is_some_and(CompressionOverride::has_level);
+        // Levels picked per response may vary from one response to the next, e.g. with the load.
+        #[cfg(any(
+            feature = "brotli",
+            feature = "deflate",
+            feature = "gzip",
+            feature = "zstd"
+        ))]
+        let cacheable = cacheable && self.level_policy.is_none();
+        let cached = match (&self.cache, resource) {
+            (Some(cache), Some(resource)) if cacheable => match ETag::from_headers(&res) {
+                Ok(Some(ETag::Strong(etag))) => Some((cache, resource, etag)),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if let Some((cache, resource, etag)) = cached {
+            let compressed = match cache.get(&resource, &etag, *encoding) {
+                Some(compressed) => compressed,
+                None => {
+                    let mut compressed = vec![];
+                    let overrides = overrides.as_ref();
+                    self.encode_observed(
+                        res.take_body(),
+                        &encoding,
+                        overrides,
+                        flush,
+                        slot,
+                        #[cfg(feature = "brotli")]
+                        content_type.as_ref(),
+                    )
+                    .read_to_end(&mut compressed)
+                    .await?;
+                    let compressed: Arc<[u8]> = compressed.into();
+                    cache.insert(&resource, &etag, *encoding, Arc::clone(&compressed));
+                    compressed
+                }
+            };
+            let len = compressed.len();
+            res.set_body(Body::from_reader(Cursor::new(compressed), Some(len)));
+        } else {
+            let body = res.take_body();
+            // Get a new Body backed by an appropriate encoder, if one is available.
+            let body = self.encode_observed(
+                body,
+                &encoding,
+                overrides.as_ref(),
+                flush,
+                slot,
+                #[cfg(feature = "brotli")]
+                content_type.as_ref(),
+            );
+            res.set_body(body);
+        }
         encoding.apply(&mut res);
+        self.report(
+            CompressionOutcome::Compressed(*encoding),
+            content_type_rule,
+            &mut res,
+        );
+        etag::rewrite(&mut res, self.etag_mode, *encoding);
 
         // End size no longer matches body size, so any existing Content-Length is useless.
         res.remove_header(headers::CONTENT_LENGTH);
 
+        // Byte ranges of the encoded body cannot be served.
+        if self.strip_accept_ranges {
+            res.remove_header(headers::ACCEPT_RANGES);
+        }
+
         Ok(res)
     }
 }
 
+/// Removes encodings which are not compiled in, as well as `identity`.
+fn compiled_in(mut encodings: Vec<Encoding>) -> Vec<Encoding> {
+    encodings.retain(|encoding| ENCODINGS.contains(encoding));
+    encodings
+}
+
+/// Adds `Accept-Encoding` to the `Vary` header, keeping any other entries once each.
+///
+/// `Vary: *` already covers every header, and so is left alone.
+fn merge_vary(res: &mut Response) -> tide::Result<()> {
+    let existing = match Vary::from_headers(&*res)? {
+        Some(existing) if existing.wildcard() => return Ok(()),
+        Some(existing) => existing,
+        None => Vary::new(),
+    };
+    let mut vary = Vary::new();
+    for name in existing.into_iter().chain([headers::ACCEPT_ENCODING]) {
+        if !vary.iter().any(|entry| *entry == name) {
+            vary.push(name)?;
+        }
+    }
+    vary.apply(res);
+    Ok(())
+}
+
 /// Returns a `Body` made from an encoder chosen from the `Encoding`.
 #[cfg_attr(
-    not(any(feature = "brotli", feature = "deflate", feature = "gzip")),
+    not(any(
+        feature = "brotli",
+        feature = "deflate",
+        feature = "gzip",
+        feature = "zstd"
+    )),
     allow(unused_variables)
 )]
 fn get_encoder(
     body: Body,
     encoding: &ContentEncoding,
     #[cfg(feature = "brotli")] brotli_quality: Level,
-    #[cfg(any(feature = "gzip", feature = "deflate"))] deflate_quality: Level,
+    #[cfg(feature = "gzip")] gzip_quality: Level,
+    #[cfg(feature = "deflate")] deflate_quality: Level,
+    #[cfg(feature = "deflate")] deflate_format: DeflateFormat,
+    #[cfg(feature = "zstd")] zstd_quality: Level,
 ) -> Body {
     #[cfg(feature = "brotli")]
     {
@@ -241,11 +862,21 @@ fn get_encoder(
         }
     }
 
+    #[cfg(feature = "zstd")]
+    {
+        if *encoding == Encoding::Zstd {
+            return Body::from_reader(
+                BufReader::new(ZstdEncoder::with_quality(body, zstd_quality)),
+                None,
+            );
+        }
+    }
+
     #[cfg(feature = "gzip")]
     {
         if *encoding == Encoding::Gzip {
             return Body::from_reader(
-                BufReader::new(GzipEncoder::with_quality(body, deflate_quality)),
+                BufReader::new(GzipEncoder::with_quality(body, gzip_quality)),
                 None,
             );
         }
@@ -254,10 +885,16 @@ fn get_encoder(
     #[cfg(feature = "deflate")]
     {
         if *encoding == Encoding::Deflate {
-            return Body::from_reader(
-                BufReader::new(DeflateEncoder::with_quality(body, deflate_quality)),
-                None,
-            );
+            return match deflate_format {
+                DeflateFormat::Zlib => Body::from_reader(
+                    BufReader::new(ZlibEncoder::with_quality(body, deflate_quality)),
+                    None,
+                ),
+                DeflateFormat::Raw => Body::from_reader(
+                    BufReader::new(DeflateEncoder::with_quality(body, deflate_quality)),
+                    None,
+                ),
+            };
         }
     }
 
@@ -270,11 +907,30 @@ fn get_encoder(
 /// Uses the defaults:
 /// - Minimum body size threshold (1024 bytes).
 /// - Check for `Content-Type` header match `^text/|\+(?:json|text|xml)$` (case insensitive).
+/// - No `Content-Type` allow or deny entries.
+/// - Responses without a `Content-Type` are compressed.
 /// - Brotli quality Fastest (level 1).
-/// - Deflate / Gzip quality Default.
+/// - Brotli window size, mode, and size hint left to the encoder.
+/// - Deflate & Gzip quality Default.
+/// - Gzip header without a modification time, and with an unknown OS.
+/// - Deflate in the zlib format.
+/// - Zstandard quality Default.
+/// - No compressed body cache.
+/// - Strong `ETag`s of compressed responses are weakened.
+/// - `Accept-Ranges` is removed from compressed responses.
+/// - Error (4xx & 5xx) responses are compressed.
+/// - `Accept-Encoding` is merged into the `Vary` header, also of responses skipped for reasons which do not depend on the request.
+/// - No `should_compress` predicate.
+/// - All compiled in encodings enabled, preferring Brotli, then Zstandard, Gzip, and Deflate.
+/// - `text/event-stream` responses are flushed after every chunk.
+/// - No observer.
+/// - The compression decision is not attached to responses.
+/// - No level policy.
+/// - No limit on compressed bodies in flight, past which Gzip at `Level::Fastest` is used.
 ///
 /// ## Example
 /// ```rust
+/// # #[cfg(feature = "regex-check")]
 /// # async_std::task::block_on(async {
 /// let mut app = tide::new();
 ///
@@ -286,20 +942,91 @@ fn get_encoder(
 ///     .build();
 ///
 /// app.with(compress_middleware);
-/// # })
+/// # });
 /// ```
 pub struct CompressMiddlewareBuilder {
     /// Minimum body size threshold in bytes. Default `1024`.
     pub threshold: usize,
     #[cfg(feature = "regex-check")]
     /// Check for `Content-Type` header match. Default: `^text/|\+(?:json|text|xml)$` (case insensitive).
+    ///
+    /// `None`, without a `content_type_matcher`, turns off checking the `Content-Type` against the MIME database too.
     pub content_type_check: Option<Regex>,
+    /// Check for `Content-Type` header match by prefix, suffix & exact rules, without regular expressions.
+    /// Default: `None`. Without the `regex-check` feature, set it to `ContentTypeMatcher::standard()` for the rules of the default regular expression.
+    ///
+    /// Types matching either this or `content_type_check` are compressible.
+    pub content_type_matcher: Option<ContentTypeMatcher>,
+    /// `Content-Type`s to always compress, taking precedence over the MIME database, `content_type_check` & `content_type_matcher`. Default: none.
+    ///
+    /// Exact MIME types, or patterns ending with `*`, e.g. `application/vnd.ourco.*`.
+    pub content_type_allow: Vec<String>,
+    /// `Content-Type`s to never compress, taking precedence over the allow entries, the MIME database, `content_type_check` & `content_type_matcher`. Default: none.
+    ///
+    /// Exact MIME types, or patterns ending with `*`. Exact allow entries take precedence over deny patterns.
+    pub content_type_deny: Vec<String>,
+    /// What happens to responses without a `Content-Type`, or labelled `application/octet-stream`, as tide does by default.
+    /// Default: `MissingContentType::Always`, compressing those without a `Content-Type`.
+    pub missing_content_type: MissingContentType,
     #[cfg(feature = "brotli")]
     /// Brotli compression quality. Default: `Level::Fastest` (level `1`).
     pub brotli_quality: Level,
+    #[cfg(feature = "brotli")]
+    /// Brotli-specific encoder parameters: window size, mode & size hint. Default: the encoder's own.
+    pub brotli_params: BrotliParams,
+    #[cfg(feature = "gzip")]
+    /// Gzip compression quality. Default: `None`, the same as `deflate_quality`.
+    pub gzip_quality: Option<Level>,
+    #[cfg(feature = "gzip")]
+    /// Gzip header fields. Default: no modification time, and an unknown OS.
+    pub gzip_header: GzipHeader,
     #[cfg(any(feature = "gzip", feature = "deflate"))]
-    /// Deflate / Gzip compression quality. Uses `Level::Default`.
+    /// Deflate compression quality, and Gzip's unless `gzip_quality` is set. Uses `Level::Default`.
     pub deflate_quality: Level,
+    #[cfg(feature = "deflate")]
+    /// Format of the `deflate` encoding. Default: `DeflateFormat::Zlib`.
+    pub deflate_format: DeflateFormat,
+    #[cfg(feature = "zstd")]
+    /// Zstandard compression quality. Uses `Level::Default`.
+    pub zstd_quality: Level,
+    /// Cache of compressed bodies for responses with a strong `ETag`. Default: `None`.
+    pub cache: Option<CompressionCache>,
+    /// How the `ETag` of a compressed response is changed. Default: `ETagMode::Weaken`.
+    pub etag_mode: ETagMode,
+    /// Remove the `Accept-Ranges` header from compressed responses. Default: `true`.
+    pub strip_accept_ranges: bool,
+    /// Compress client error (4xx) and server error (5xx) responses. Default: `true`.
+    ///
+    /// Informational (1xx), `204 No Content`, and `304 Not Modified` responses are never compressed.
+    pub compress_errors: bool,
+    /// Add `Vary: Accept-Encoding` to responses skipped for reasons which do not depend on the request. Default: `true`.
+    ///
+    /// Those reasons are the status, an existing `Content-Encoding`, the body size, and the `Content-Type`.
+    pub vary_on_skip: bool,
+    /// Predicate deciding whether a response may be compressed. Default: `None`.
+    pub should_compress: Option<ShouldCompress>,
+    /// Enabled encodings, in order of preference. Default: all which are compiled in, in the order `br`, `zstd`, `gzip`, `deflate`.
+    ///
+    /// The client's `Accept-Encoding` weights take precedence, this order only breaks ties.
+    pub encodings: Vec<Encoding>,
+    /// When the compressor of a `text/event-stream` response is flushed. Default: `FlushMode::Immediate`.
+    pub streaming_flush: FlushMode,
+    /// Receives metrics of compressed & skipped responses. Default: `None`.
+    pub observer: Option<Arc<dyn CompressionObserver>>,
+    /// Whether the compression decision is attached to responses, for debugging. Default: `DebugDecision::Off`.
+    pub debug_decision: DebugDecision,
+    #[cfg(any(
+        feature = "brotli",
+        feature = "deflate",
+        feature = "gzip",
+        feature = "zstd"
+    ))]
+    /// Picks the compression level per response, taking precedence over the quality of each encoding. Default: `None`.
+    pub level_policy: Option<Arc<dyn LevelPolicy>>,
+    /// Maximum number of compressed bodies in flight at once, beyond which responses fall back. Default: `None`.
+    pub max_in_flight: Option<usize>,
+    /// What happens to responses beyond `max_in_flight`. Default: `OverloadFallback::Cheaper`.
+    pub overload_fallback: OverloadFallback,
 }
 
 impl Default for CompressMiddlewareBuilder {
@@ -313,10 +1040,43 @@ impl Default for CompressMiddlewareBuilder {
                     .build()
                     .expect("Constant regular expression defined in Tide-Compress's source code"),
             ),
+            content_type_matcher: None,
+            content_type_allow: vec![],
+            content_type_deny: vec![],
+            missing_content_type: MissingContentType::default(),
             #[cfg(feature = "brotli")]
             brotli_quality: Level::Fastest,
+            #[cfg(feature = "brotli")]
+            brotli_params: BrotliParams::default(),
+            #[cfg(feature = "gzip")]
+            gzip_quality: None,
+            #[cfg(feature = "gzip")]
+            gzip_header: GzipHeader::default(),
             #[cfg(any(feature = "gzip", feature = "deflate"))]
             deflate_quality: Level::Default,
+            #[cfg(feature = "deflate")]
+            deflate_format: DeflateFormat::default(),
+            #[cfg(feature = "zstd")]
+            zstd_quality: Level::Default,
+            cache: None,
+            etag_mode: ETagMode::default(),
+            strip_accept_ranges: true,
+            compress_errors: true,
+            vary_on_skip: true,
+            should_compress: None,
+            encodings: ENCODINGS.to_vec(),
+            streaming_flush: FlushMode::default(),
+            observer: None,
+            debug_decision: DebugDecision::default(),
+            #[cfg(any(
+                feature = "brotli",
+                feature = "deflate",
+                feature = "gzip",
+                feature = "zstd"
+            ))]
+            level_policy: None,
+            max_in_flight: None,
+            overload_fallback: OverloadFallback::default(),
         }
     }
 }
@@ -341,6 +1101,50 @@ impl CompressMiddlewareBuilder {
         self
     }
 
+    /// Sets the `Content-Type` header (MIME) check rules, which need no regular expressions.
+    ///
+    /// Types matching either these rules or the `content_type_check` regular expression are compressible.
+    /// See [`ContentTypeMatcher`].
+    pub fn content_type_matcher(
+        mut self,
+        content_type_matcher: Option<ContentTypeMatcher>,
+    ) -> Self {
+        self.content_type_matcher = content_type_matcher;
+        self
+    }
+
+    /// Adds a `Content-Type` to always compress: an exact MIME type, or a pattern ending with `*`, e.g. `application/vnd.ourco.*`.
+    ///
+    /// `Content-Type`s are checked against, in order:
+    /// 1. Exact deny entries.
+    /// 2. Exact allow entries.
+    /// 3. Deny patterns.
+    /// 4. Allow patterns.
+    /// 5. The MIME database, with the `db-check` feature, unless `content_type_check` is set to `None` without a `content_type_matcher`.
+    /// 6. The `content_type_check` regular expression and the `content_type_matcher` rules.
+    ///
+    /// The first match decides. The allow & deny entries apply even when neither `content_type_check` nor `content_type_matcher` is set.
+    pub fn allow_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type_allow.push(content_type.into());
+        self
+    }
+
+    /// Adds a `Content-Type` to never compress: an exact MIME type, or a pattern ending with `*`, e.g. `image/*`.
+    ///
+    /// See [`allow_content_type`](Self::allow_content_type) for the order in which entries are checked.
+    pub fn deny_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type_deny.push(content_type.into());
+        self
+    }
+
+    /// Sets what happens to responses without a `Content-Type`, or labelled `application/octet-stream`: compress them, skip them, or sniff the body.
+    ///
+    /// See [`MissingContentType`].
+    pub fn missing_content_type(mut self, missing_content_type: MissingContentType) -> Self {
+        self.missing_content_type = missing_content_type;
+        self
+    }
+
     #[cfg(feature = "brotli")]
     /// Sets the compression level for Brotli.
     pub fn brotli_quality(mut self, quality: Level) -> Self {
@@ -348,13 +1152,168 @@ impl CompressMiddlewareBuilder {
         self
     }
 
+    #[cfg(feature = "brotli")]
+    /// Sets the Brotli-specific encoder parameters.
+    ///
+    /// See [`BrotliParams`].
+    pub fn brotli_params(mut self, brotli_params: BrotliParams) -> Self {
+        self.brotli_params = brotli_params;
+        self
+    }
+
     #[cfg(any(feature = "gzip", feature = "deflate"))]
     /// Sets the compression level for both Deflate and Gzip.
+    ///
+    /// Use [`gzip_quality`](Self::gzip_quality) to set a different level for Gzip.
     pub fn deflate_quality(mut self, quality: Level) -> Self {
         self.deflate_quality = quality;
         self
     }
 
+    #[cfg(feature = "gzip")]
+    /// Sets the compression level for Gzip only, rather than that of [`deflate_quality`](Self::deflate_quality).
+    pub fn gzip_quality(mut self, quality: Level) -> Self {
+        self.gzip_quality = Some(quality);
+        self
+    }
+
+    #[cfg(feature = "gzip")]
+    /// Sets the Gzip header fields, e.g. for output which is reproducible across hosts.
+    ///
+    /// See [`GzipHeader`].
+    pub fn gzip_header(mut self, gzip_header: GzipHeader) -> Self {
+        self.gzip_header = gzip_header;
+        self
+    }
+
+    #[cfg(feature = "deflate")]
+    /// Sets the format of the `deflate` encoding, zlib-wrapped or raw for legacy clients.
+    ///
+    /// See [`DeflateFormat`].
+    pub fn deflate_format(mut self, deflate_format: DeflateFormat) -> Self {
+        self.deflate_format = deflate_format;
+        self
+    }
+
+    #[cfg(feature = "zstd")]
+    /// Sets the compression level for Zstandard.
+    pub fn zstd_quality(mut self, quality: Level) -> Self {
+        self.zstd_quality = quality;
+        self
+    }
+
+    /// Sets the cache of compressed bodies for responses with a strong `ETag`.
+    ///
+    /// See [`CompressionCache`].
+    pub fn cache(mut self, cache: Option<CompressionCache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Sets how the `ETag` of a compressed response is changed.
+    ///
+    /// See [`ETagMode`].
+    pub fn etag_mode(mut self, etag_mode: ETagMode) -> Self {
+        self.etag_mode = etag_mode;
+        self
+    }
+
+    /// Sets whether the `Accept-Ranges` header is removed from compressed responses.
+    pub fn strip_accept_ranges(mut self, strip_accept_ranges: bool) -> Self {
+        self.strip_accept_ranges = strip_accept_ranges;
+        self
+    }
+
+    /// Sets whether client error (4xx) and server error (5xx) responses are compressed.
+    pub fn compress_errors(mut self, compress_errors: bool) -> Self {
+        self.compress_errors = compress_errors;
+        self
+    }
+
+    /// Sets whether `Vary: Accept-Encoding` is added to responses skipped for reasons which do not depend on the request.
+    ///
+    /// Those reasons are the status, an existing `Content-Encoding`, the body size, and the `Content-Type`.
+    /// Such responses are the same for every client, and so are best cached without a `Vary`.
+    pub fn vary_on_skip(mut self, vary_on_skip: bool) -> Self {
+        self.vary_on_skip = vary_on_skip;
+        self
+    }
+
+    /// Sets a predicate deciding whether a response may be compressed.
+    ///
+    /// See [`ShouldCompress`].
+    pub fn should_compress<F>(mut self, should_compress: F) -> Self
+    where
+        F: Fn(&RequestParts, &Response) -> bool + Send + Sync + 'static,
+    {
+        self.should_compress = Some(ShouldCompress::new(should_compress));
+        self
+    }
+
+    /// Sets the enabled encodings, in order of preference.
+    ///
+    /// The client's `Accept-Encoding` weights take precedence, this order only breaks ties.
+    /// Encodings which are not compiled in are ignored.
+    pub fn encodings(mut self, encodings: impl Into<Vec<Encoding>>) -> Self {
+        self.encodings = encodings.into();
+        self
+    }
+
+    /// Sets when the compressor of a streaming (`text/event-stream`) response is flushed.
+    ///
+    /// See [`FlushMode`].
+    pub fn streaming_flush(mut self, streaming_flush: FlushMode) -> Self {
+        self.streaming_flush = streaming_flush;
+        self
+    }
+
+    /// Sets the observer which receives metrics of compressed & skipped responses.
+    ///
+    /// See [`CompressionObserver`].
+    pub fn observer(mut self, observer: Arc<dyn CompressionObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Sets whether the compression decision is attached to responses, for debugging.
+    ///
+    /// See [`CompressionDecision`].
+    pub fn debug_decision(mut self, debug_decision: DebugDecision) -> Self {
+        self.debug_decision = debug_decision;
+        self
+    }
+
+    #[cfg(any(
+        feature = "brotli",
+        feature = "deflate",
+        feature = "gzip",
+        feature = "zstd"
+    ))]
+    /// Sets the policy picking the compression level per response.
+    ///
+    /// See [`LevelPolicy`] and [`SizeLevelPolicy`](crate::SizeLevelPolicy).
+    pub fn level_policy(mut self, level_policy: Arc<dyn LevelPolicy>) -> Self {
+        self.level_policy = Some(level_policy);
+        self
+    }
+
+    /// Sets the maximum number of compressed bodies in flight at once.
+    ///
+    /// A body is in flight from when its compression starts until it is dropped, i.e. sent or abandoned.
+    /// Beyond the maximum, responses fall back as set by [`overload_fallback`](Self::overload_fallback).
+    pub fn max_in_flight(mut self, max_in_flight: Option<usize>) -> Self {
+        self.max_in_flight = max_in_flight;
+        self
+    }
+
+    /// Sets what happens to responses beyond `max_in_flight`.
+    ///
+    /// See [`OverloadFallback`].
+    pub fn overload_fallback(mut self, overload_fallback: OverloadFallback) -> Self {
+        self.overload_fallback = overload_fallback;
+        self
+    }
+
     /// Construct a middleware instance from this builder.
     pub fn build(self) -> CompressMiddleware {
         self.into()
@@ -367,13 +1326,46 @@ impl From<CompressMiddlewareBuilder> for CompressMiddleware {
             threshold: builder.threshold,
             #[cfg(feature = "regex-check")]
             content_type_check: builder.content_type_check,
-            #[cfg(feature = "regex-check")]
-            extract_type_regex: Regex::new(EXTRACT_TYPE_PATTERN)
-                .expect("Constant regular expression defined in Tide-Compress's source code"),
+            content_type_matcher: builder.content_type_matcher,
+            content_type_lists: ContentTypeLists::new(
+                &builder.content_type_allow,
+                &builder.content_type_deny,
+            ),
+            missing_content_type: builder.missing_content_type,
             #[cfg(feature = "brotli")]
             brotli_quality: builder.brotli_quality,
-            #[cfg(any(feature = "gzip", feature = "deflate"))]
+            #[cfg(feature = "brotli")]
+            brotli_params: builder.brotli_params,
+            #[cfg(feature = "gzip")]
+            gzip_quality: builder.gzip_quality.unwrap_or(builder.deflate_quality),
+            #[cfg(feature = "gzip")]
+            gzip_header: builder.gzip_header,
+            #[cfg(feature = "deflate")]
             deflate_quality: builder.deflate_quality,
+            #[cfg(feature = "deflate")]
+            deflate_format: builder.deflate_format,
+            #[cfg(feature = "zstd")]
+            zstd_quality: builder.zstd_quality,
+            cache: builder.cache,
+            etag_mode: builder.etag_mode,
+            strip_accept_ranges: builder.strip_accept_ranges,
+            compress_errors: builder.compress_errors,
+            vary_on_skip: builder.vary_on_skip,
+            should_compress: builder.should_compress,
+            encodings: compiled_in(builder.encodings),
+            streaming_flush: builder.streaming_flush,
+            observer: builder.observer,
+            debug_decision: builder.debug_decision,
+            #[cfg(any(
+                feature = "brotli",
+                feature = "deflate",
+                feature = "gzip",
+                feature = "zstd"
+            ))]
+            level_policy: builder.level_policy,
+            max_in_flight: builder.max_in_flight,
+            overload_fallback: builder.overload_fallback,
+            in_flight: InFlight::default(),
         }
     }
 }
@@ -0,0 +1,44 @@
+use crate::codegen_database::MIME_DB;
+pub(crate) use crate::data_kind::DataKind;
+
+/// Whether a MIME type is compressible, as per the database.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Compressibility {
+    Compressible,
+    Incompressible,
+    /// Listed, but without a verdict.
+    Unknown,
+}
+
+/// A single MIME type in the database.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct MimeEntry {
+    pub(crate) compressibility: Compressibility,
+    #[cfg_attr(
+        not(feature = "brotli"),
+        allow(dead_code, reason = "only tunes Brotli")
+    )]
+    pub(crate) kind: DataKind,
+}
+
+impl MimeEntry {
+    pub(crate) const fn new(compressibility: Compressibility, kind: DataKind) -> Self {
+        Self {
+            compressibility,
+            kind,
+        }
+    }
+}
+
+/// Looks up a MIME type essence, e.g. `text/html`.
+///
+/// See `codegen_database.rs` & the `generate-database` directory.
+/// The database is generated from jshttp/mime-db into a map with a perfect hash function, with roughly O(1) lookup time.
+pub(crate) fn lookup(essence: &str) -> Option<&'static MimeEntry> {
+    MIME_DB.get(essence)
+}
+
+/// Whether a MIME type essence is compressible, as per the database.
+pub(crate) fn compressibility(essence: &str) -> Compressibility {
+    lookup(essence).map_or(Compressibility::Unknown, |entry| entry.compressibility)
+}
@@ -0,0 +1,82 @@
+use tide::http::content::{AcceptEncoding, Encoding};
+use tide::http::{headers, Headers};
+
+/// The weight of `identity` when the client did not list it: acceptable, but only when nothing else is.
+const IDENTITY_FALLBACK: f32 = f32::MIN_POSITIVE;
+
+/// A request's `Accept-Encoding`, along with the weight of any `*` wildcard, which `AcceptEncoding` drops when weighted.
+pub(crate) struct Accepts {
+    encodings: AcceptEncoding,
+    wildcard: Option<f32>,
+}
+
+impl Accepts {
+    /// Reads the `Accept-Encoding` header, if there is one.
+    pub(crate) fn from_headers(headers: impl AsRef<Headers>) -> tide::Result<Option<Self>> {
+        let encodings = match AcceptEncoding::from_headers(&headers)? {
+            Some(encodings) => encodings,
+            None => return Ok(None),
+        };
+        let wildcard = headers
+            .as_ref()
+            .get(headers::ACCEPT_ENCODING)
+            .into_iter()
+            .flatten()
+            .flat_map(|value| value.as_str().split(','))
+            .find_map(wildcard_weight);
+        Ok(Some(Self {
+            encodings,
+            wildcard,
+        }))
+    }
+
+    /// The client's weight for an encoding, if acceptable at all.
+    ///
+    /// As per RFC 9110, `identity` is acceptable unless excluded with `identity;q=0` or `*;q=0`.
+    fn weight(&self, encoding: Encoding) -> Option<f32> {
+        match self
+            .encodings
+            .iter()
+            .find(|proposal| *proposal.encoding() == encoding)
+        {
+            Some(proposal) => Some(proposal.weight().unwrap_or(1.0)),
+            None => match self.wildcard {
+                Some(weight) => Some(weight),
+                None if self.encodings.wildcard() => Some(1.0),
+                None if encoding == Encoding::Identity => Some(IDENTITY_FALLBACK),
+                None => None,
+            },
+        }
+    }
+}
+
+/// The weight of a `*` entry of `Accept-Encoding`, e.g. `*;q=0`.
+fn wildcard_weight(entry: &str) -> Option<f32> {
+    let mut parts = entry.split(';');
+    if parts.next()?.trim() != "*" {
+        return None;
+    }
+    match parts.find_map(|param| param.trim().strip_prefix("q=")) {
+        Some(weight) => weight.trim().parse().ok(),
+        None => Some(1.0),
+    }
+}
+
+/// Chooses the `available` encoding the client weights highest, with ties going to the earliest in `available`.
+///
+/// Encodings with a weight of `0` are unacceptable, as are those not listed unless the client sent a `*` wildcard,
+/// except for `identity`, which is chosen when nothing else is acceptable, unless excluded.
+pub(crate) fn negotiate(accepts: &Accepts, available: &[Encoding]) -> Option<Encoding> {
+    let mut best: Option<(Encoding, f32)> = None;
+    for encoding in available {
+        let weight = match accepts.weight(*encoding) {
+            Some(weight) if weight > 0.0 => weight,
+            _ => continue,
+        };
+        match best {
+            Some((_, best_weight)) if best_weight >= weight => {}
+            _ => best = Some((*encoding, weight)),
+        }
+    }
+    best.map(|(encoding, _)| encoding)
+}
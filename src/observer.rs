@@ -0,0 +1,281 @@
+use std::convert::TryInto;
+use std::fmt::Debug;
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use futures_lite::io::{AsyncBufRead, AsyncRead};
+use tide::http::content::Encoding;
+use tide::http::Body;
+
+/// Receives metrics from [`CompressMiddleware`](crate::CompressMiddleware), e.g. to forward them to a metrics system.
+///
+/// All methods do nothing by default. They are called from within request handling, and so should be cheap.
+///
+/// ## Example
+/// ```rust
+/// # async_std::task::block_on(async {
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// use std::sync::Arc;
+///
+/// use tide::http::content::Encoding;
+/// use tide_compress::{CompressionObserver, EncoderStats};
+///
+/// #[derive(Debug, Default)]
+/// struct Savings(AtomicU64);
+///
+/// impl CompressionObserver for Savings {
+///     fn encoded(&self, _encoding: Encoding, stats: EncoderStats) {
+///         let saved = stats.bytes_in.saturating_sub(stats.bytes_out);
+///         self.0.fetch_add(saved, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let mut app = tide::new();
+///
+/// let savings = Arc::new(Savings::default());
+///
+/// app.with(
+///     tide_compress::CompressMiddleware::builder()
+///         .observer(savings.clone())
+///         .build(),
+/// );
+/// # })
+/// ```
+pub trait CompressionObserver: Debug + Send + Sync + 'static {
+    /// A response is being compressed with the `Encoding`.
+    fn compressed(&self, encoding: Encoding) {
+        let _ = encoding;
+    }
+
+    /// A response was left uncompressed.
+    fn skipped(&self, reason: SkipReason) {
+        let _ = reason;
+    }
+
+    /// An encoder has finished, or was dropped before finishing, e.g. because the client went away.
+    ///
+    /// Not called for bodies served from a [`CompressionCache`](crate::CompressionCache).
+    fn encoded(&self, encoding: Encoding, stats: EncoderStats) {
+        let _ = (encoding, stats);
+    }
+
+    /// The `max_in_flight` cap was reached, and the response falls back to a cheaper encoding or none.
+    ///
+    /// Followed by `compressed` with the fallback encoding, or `skipped` with [`SkipReason::Overloaded`].
+    fn overloaded(&self) {}
+}
+
+/// Why a response was left uncompressed, see [`CompressionObserver::skipped`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SkipReason {
+    /// The request was a `HEAD` request.
+    Head,
+    /// The request had no `Accept-Encoding` header.
+    NoAcceptEncoding,
+    /// A [`CompressionOverride`](crate::CompressionOverride) disabled compression.
+    Override,
+    /// The response had a `Cache-Control: no-transform` header.
+    NoTransform,
+    /// The response was a range (`206 Partial Content`) response.
+    Range,
+    /// The response status excluded compression, e.g. `304 Not Modified`.
+    Status,
+    /// The response already had a `Content-Encoding`.
+    AlreadyEncoded,
+    /// The `should_compress` predicate returned `false`.
+    Predicate,
+    /// The response body was below the minimum size threshold.
+    BelowThreshold,
+    /// The response `Content-Type` was not compressible.
+    ContentType,
+    /// The client preferred the `identity` encoding.
+    Identity,
+    /// Too many responses were already being compressed, and no cheaper fallback was acceptable.
+    Overloaded,
+}
+
+impl SkipReason {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            SkipReason::Head => "head",
+            SkipReason::NoAcceptEncoding => "no-accept-encoding",
+            SkipReason::Override => "override",
+            SkipReason::NoTransform => "no-transform",
+            SkipReason::Range => "range",
+            SkipReason::Status => "status",
+            SkipReason::AlreadyEncoded => "already-encoded",
+            SkipReason::Predicate => "predicate",
+            SkipReason::BelowThreshold => "below-threshold",
+            SkipReason::ContentType => "content-type",
+            SkipReason::Identity => "identity",
+            SkipReason::Overloaded => "overloaded",
+        }
+    }
+}
+
+/// Statistics of a single encoder, see [`CompressionObserver::encoded`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EncoderStats {
+    /// Uncompressed bytes read from the response body.
+    pub bytes_in: u64,
+    /// Compressed bytes produced.
+    pub bytes_out: u64,
+    /// Time spent in the encoder, excluding time spent producing the response body itself.
+    pub duration: Duration,
+}
+
+/// Counters shared between the reader of the uncompressed body and the reader of the compressed body.
+#[derive(Debug, Default)]
+struct Shared {
+    bytes_in: AtomicU64,
+    /// Nanoseconds spent producing the uncompressed body.
+    body_nanos: AtomicU64,
+}
+
+/// Wraps the uncompressed body, counting bytes and time spent producing it.
+#[derive(Debug)]
+struct CountingBody {
+    body: Body,
+    shared: Arc<Shared>,
+}
+
+/// Wraps the compressed body, reporting to the observer once dropped.
+#[derive(Debug)]
+struct ObservedBody {
+    body: Body,
+    shared: Arc<Shared>,
+    observer: Arc<dyn CompressionObserver>,
+    encoding: Encoding,
+    bytes_out: u64,
+    elapsed: Duration,
+}
+
+/// Returns a `Body` made by `encode`, reporting its statistics to the observer.
+pub(crate) fn observe(
+    body: Body,
+    encoding: Encoding,
+    observer: &Arc<dyn CompressionObserver>,
+    encode: impl FnOnce(Body) -> Body,
+) -> Body {
+    let shared = Arc::new(Shared::default());
+    let len = body.len();
+    let body = encode(Body::from_reader(
+        CountingBody {
+            body,
+            shared: Arc::clone(&shared),
+        },
+        len,
+    ));
+    Body::from_reader(
+        ObservedBody {
+            body,
+            shared,
+            observer: Arc::clone(observer),
+            encoding,
+            bytes_out: 0,
+            elapsed: Duration::ZERO,
+        },
+        None,
+    )
+}
+
+impl CountingBody {
+    fn timed<T>(&mut self, f: impl FnOnce(Pin<&mut Body>) -> T) -> T {
+        let start = Instant::now();
+        let result = f(Pin::new(&mut self.body));
+        let nanos = start.elapsed().as_nanos().try_into().unwrap_or(u64::MAX);
+        self.shared.body_nanos.fetch_add(nanos, Ordering::Relaxed);
+        result
+    }
+}
+
+impl AsyncRead for CountingBody {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let result = self.timed(|body| body.poll_read(cx, buf));
+        if let Poll::Ready(Ok(len)) = result {
+            self.shared
+                .bytes_in
+                .fetch_add(len as u64, Ordering::Relaxed);
+        }
+        result
+    }
+}
+
+impl AsyncBufRead for CountingBody {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        let start = Instant::now();
+        let result = Pin::new(&mut this.body).poll_fill_buf(cx);
+        let nanos = start.elapsed().as_nanos().try_into().unwrap_or(u64::MAX);
+        this.shared.body_nanos.fetch_add(nanos, Ordering::Relaxed);
+        result
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        self.shared
+            .bytes_in
+            .fetch_add(amt as u64, Ordering::Relaxed);
+        Pin::new(&mut self.body).consume(amt)
+    }
+}
+
+impl ObservedBody {
+    fn timed<T>(&mut self, f: impl FnOnce(Pin<&mut Body>) -> T) -> T {
+        let start = Instant::now();
+        let result = f(Pin::new(&mut self.body));
+        self.elapsed += start.elapsed();
+        result
+    }
+}
+
+impl AsyncRead for ObservedBody {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let result = self.timed(|body| body.poll_read(cx, buf));
+        if let Poll::Ready(Ok(len)) = result {
+            self.bytes_out += len as u64;
+        }
+        result
+    }
+}
+
+impl AsyncBufRead for ObservedBody {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        let start = Instant::now();
+        let result = Pin::new(&mut this.body).poll_fill_buf(cx);
+        this.elapsed += start.elapsed();
+        result
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        self.bytes_out += amt as u64;
+        Pin::new(&mut self.body).consume(amt)
+    }
+}
+
+impl Drop for ObservedBody {
+    fn drop(&mut self) {
+        let body = Duration::from_nanos(self.shared.body_nanos.load(Ordering::Relaxed));
+        self.observer.encoded(
+            self.encoding,
+            EncoderStats {
+                bytes_in: self.shared.bytes_in.load(Ordering::Relaxed),
+                bytes_out: self.bytes_out,
+                duration: self.elapsed.saturating_sub(body),
+            },
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use tide::http::content::Encoding;
+
+#[cfg(any(
+    feature = "brotli",
+    feature = "deflate",
+    feature = "gzip",
+    feature = "zstd"
+))]
+use async_compression::Level;
+
+/// Per-route compression settings, which take precedence over those of [`CompressMiddleware`](crate::CompressMiddleware).
+///
+/// Insert into either the `Request` or the `Response` extensions. If both are present, the `Response`'s is used.
+///
+/// The `Request` extension is read before the request is passed on, so it is only seen when inserted by
+/// app-level middleware registered before `CompressMiddleware`. Route handlers, and middleware added to
+/// a route with `.with()`, run later, and so must insert the override into the `Response` extensions.
+///
+/// ## Example
+/// ```rust
+/// # async_std::task::block_on(async {
+/// use tide_compress::CompressionOverride;
+///
+/// let mut app = tide::new();
+///
+/// app.with(tide_compress::CompressMiddleware::new());
+/// app.at("/download").get(|_| async {
+///     let mut res = tide::Response::new(200);
+///     // Already encrypted, and so incompressible.
+///     res.insert_ext(CompressionOverride::new().enabled(false));
+///     Ok(res)
+/// });
+/// # })
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CompressionOverride {
+    /// `Some(false)` never compresses the response.
+    /// `Some(true)` compresses regardless of the body size threshold and `Content-Type` checks.
+    pub enabled: Option<bool>,
+    #[cfg(any(
+        feature = "brotli",
+        feature = "deflate",
+        feature = "gzip",
+        feature = "zstd"
+    ))]
+    /// Compression level, for whichever encoding is chosen.
+    pub level: Option<Level>,
+    /// Encodings which may be chosen, in order of preference.
+    pub encodings: Option<Vec<Encoding>>,
+}
+
+impl CompressionOverride {
+    /// Make a new override, which does not change anything until configured.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the response is compressed, overriding the body size threshold and `Content-Type` checks.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    #[cfg(any(
+        feature = "brotli",
+        feature = "deflate",
+        feature = "gzip",
+        feature = "zstd"
+    ))]
+    /// Sets the compression level, for whichever encoding is chosen.
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Sets the encodings which may be chosen, in order of preference.
+    pub fn encodings(mut self, encodings: impl Into<Vec<Encoding>>) -> Self {
+        self.encodings = Some(encodings.into());
+        self
+    }
+
+    /// Whether the compression level is overridden.
+    pub(crate) fn has_level(&self) -> bool {
+        #[cfg(any(
+            feature = "brotli",
+            feature = "deflate",
+            feature = "gzip",
+            feature = "zstd"
+        ))]
+        return self.level.is_some();
+        #[cfg(not(any(
+            feature = "brotli",
+            feature = "deflate",
+            feature = "gzip",
+            feature = "zstd"
+        )))]
+        return false;
+    }
+}
@@ -0,0 +1,173 @@
+use std::ffi::OsStr;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tide::http::conditional::Vary;
+use tide::http::content::Encoding;
+use tide::http::{headers, mime, Body, Mime, StatusCode};
+use tide::{Endpoint, Request, Response};
+
+use crate::negotiate::{negotiate, Accepts};
+
+/// Precompressed sibling file extensions, in order of server preference.
+///
+/// This mirrors the preference order of [`CompressMiddleware`](crate::CompressMiddleware).
+/// Unlike on-the-fly compression, serving precompressed files does not require any encoder to be compiled in.
+const SIBLINGS: &[(Encoding, &str)] = &[
+    (Encoding::Brotli, "br"),
+    (Encoding::Zstd, "zst"),
+    (Encoding::Gzip, "gz"),
+];
+
+/// An endpoint for serving a directory of static files, preferring precompressed siblings.
+///
+/// For a requested `foo.js`, a `foo.js.br`, `foo.js.zst`, or `foo.js.gz` next to it is served instead,
+/// as negotiated from the request's `Accept-Encoding` header, with the matching `Content-Encoding` set
+/// and the `Content-Type` of the original file.
+///
+/// If no acceptable sibling exists the original file is served as-is, which can then be compressed
+/// on the fly by [`CompressMiddleware`](crate::CompressMiddleware).
+///
+/// ## Example
+/// ```rust,no_run
+/// # fn main() -> std::io::Result<()> {
+/// let mut app = tide::new();
+///
+/// app.with(tide_compress::CompressMiddleware::new());
+/// app.at("/static/*")
+///     .get(tide_compress::ServeDirCompressed::new("/static/", "public/")?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ServeDirCompressed {
+    prefix: String,
+    dir: PathBuf,
+}
+
+impl ServeDirCompressed {
+    /// Creates a new ServeDirCompressed, serving files from `dir` for requests under the url path `prefix`.
+    ///
+    /// Errors if `dir` does not exist.
+    pub fn new(prefix: impl Into<String>, dir: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self {
+            prefix: prefix.into(),
+            dir: dir.as_ref().canonicalize()?,
+        })
+    }
+
+    /// Resolves the requested path within the served directory.
+    /// Returns `None` if the path would escape the directory.
+    fn file_path(&self, path: &str) -> Option<PathBuf> {
+        let path = path.strip_prefix(&self.prefix).unwrap_or(path);
+        let path = path.trim_start_matches('/');
+
+        let mut file_path = self.dir.clone();
+        for part in Path::new(path) {
+            if part == OsStr::new(".") {
+                continue;
+            } else if part == OsStr::new("..") {
+                file_path.pop();
+            } else {
+                file_path.push(part);
+            }
+        }
+
+        if file_path.starts_with(&self.dir) {
+            Some(file_path)
+        } else {
+            None
+        }
+    }
+}
+
+#[tide::utils::async_trait]
+impl<State: Clone + Send + Sync + 'static> Endpoint<State> for ServeDirCompressed {
+    async fn call(&self, req: Request<State>) -> tide::Result {
+        let file_path = match self.file_path(req.url().path()) {
+            Some(file_path) => file_path,
+            None => return Ok(Response::new(StatusCode::Forbidden)),
+        };
+
+        let mut res = match Accepts::from_headers(&req)? {
+            Some(accepts) => {
+                let mut available: Vec<Encoding> = SIBLINGS
+                    .iter()
+                    .map(|(encoding, _)| *encoding)
+                    .chain(Some(Encoding::Identity))
+                    .collect();
+
+                loop {
+                    // Anything not satisfiable by a sibling is left to the original file,
+                    // and so to `CompressMiddleware`'s own negotiation.
+                    let encoding = negotiate(&accepts, &available).unwrap_or(Encoding::Identity);
+                    if encoding == Encoding::Identity {
+                        break serve_file(&file_path).await?;
+                    }
+
+                    match serve_sibling(&file_path, encoding).await? {
+                        Some(res) => break res,
+                        // Not available, try the next best.
+                        None => available.retain(|available| *available != encoding),
+                    }
+                }
+            }
+            None => serve_file(&file_path).await?,
+        };
+
+        let mut vary = Vary::new();
+        vary.push(headers::ACCEPT_ENCODING)?;
+        vary.apply(&mut res);
+
+        Ok(res)
+    }
+}
+
+/// Serves the original file, or a `404 Not Found` if it does not exist.
+async fn serve_file(file_path: &Path) -> tide::Result<Response> {
+    match Body::from_file(file_path).await {
+        Ok(body) => Ok(Response::builder(StatusCode::Ok).body(body).build()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Response::new(StatusCode::NotFound)),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Serves the precompressed sibling of a file for the `Encoding`, if one exists.
+async fn serve_sibling(file_path: &Path, encoding: Encoding) -> tide::Result<Option<Response>> {
+    let extension = match SIBLINGS.iter().find(|(sibling, _)| *sibling == encoding) {
+        Some((_, extension)) => extension,
+        None => return Ok(None),
+    };
+
+    let mut sibling_path = file_path.as_os_str().to_owned();
+    sibling_path.push(".");
+    sibling_path.push(extension);
+
+    let mut body = match Body::from_file(PathBuf::from(sibling_path)).await {
+        Ok(body) => body,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    body.set_mime(original_mime(file_path).await);
+
+    let mut res = Response::builder(StatusCode::Ok).body(body).build();
+    res.insert_header(headers::CONTENT_ENCODING, encoding.to_string());
+    Ok(Some(res))
+}
+
+/// The `Content-Type` the original file would be served with.
+async fn original_mime(file_path: &Path) -> Mime {
+    if let Some(mime) = file_path
+        .extension()
+        .and_then(OsStr::to_str)
+        .and_then(Mime::from_extension)
+    {
+        return mime;
+    }
+
+    // Sniff the original file if the extension was not helpful.
+    match Body::from_file(file_path).await {
+        Ok(body) => body.mime().clone(),
+        Err(_) => mime::BYTE_STREAM,
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+use std::sync::Arc;
+
+use tide::http::headers::{HeaderName, HeaderValues, Headers};
+use tide::http::Method;
+use tide::{Request, Response};
+
+type Predicate = dyn Fn(&RequestParts, &Response) -> bool + Send + Sync + 'static;
+
+/// A user-supplied predicate deciding whether a response may be compressed.
+///
+/// Called with the parts of the request and the response, after the response's status & existing
+/// `Content-Encoding` have been checked but before the body size threshold & `Content-Type` checks.
+/// Returning `false` leaves the response uncompressed, returning `true` continues with the usual checks.
+///
+/// ## Example
+/// ```rust
+/// # async_std::task::block_on(async {
+/// use tide::http::headers;
+///
+/// let mut app = tide::new();
+///
+/// // Only compress `/api/` responses for authenticated clients.
+/// app.with(
+///     tide_compress::CompressMiddleware::builder()
+///         .should_compress(|req, _res| {
+///             !req.path().starts_with("/api/") || req.header(headers::AUTHORIZATION).is_some()
+///         })
+///         .build(),
+/// );
+/// # })
+/// ```
+#[derive(Clone)]
+pub struct ShouldCompress {
+    predicate: Arc<Predicate>,
+}
+
+impl ShouldCompress {
+    /// Wraps a predicate closure.
+    pub fn new<F>(predicate: F) -> Self
+    where
+        F: Fn(&RequestParts, &Response) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            predicate: Arc::new(predicate),
+        }
+    }
+
+    pub(crate) fn call(&self, req: &RequestParts, res: &Response) -> bool {
+        (self.predicate)(req, res)
+    }
+}
+
+impl fmt::Debug for ShouldCompress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShouldCompress").finish_non_exhaustive()
+    }
+}
+
+/// The parts of a request given to a [`ShouldCompress`] predicate.
+///
+/// Captured before the request is passed on, as the request itself is consumed by the route.
+#[derive(Clone, Debug)]
+pub struct RequestParts {
+    method: Method,
+    path: String,
+    headers: Headers,
+}
+
+impl RequestParts {
+    pub(crate) fn new<State>(req: &Request<State>) -> Self {
+        let headers: &Headers = req.as_ref();
+        Self {
+            method: req.method(),
+            path: req.url().path().to_owned(),
+            headers: headers.clone(),
+        }
+    }
+
+    /// The request method.
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    /// The request url path.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Gets a request header.
+    pub fn header(&self, name: impl Into<HeaderName>) -> Option<&HeaderValues> {
+        self.headers.get(name)
+    }
+
+    /// All of the request headers.
+    pub fn headers(&self) -> &Headers {
+        &self.headers
+    }
+}
@@ -0,0 +1,67 @@
+use std::io;
+
+use futures_lite::io::AsyncBufReadExt;
+use tide::http::Body;
+
+/// Signatures at the start of compressed, or otherwise binary, formats.
+const MAGIC: &[&[u8]] = &[
+    b"\x89PNG\r\n\x1a\n",  // PNG
+    b"\xff\xd8\xff",       // JPEG
+    b"GIF87a",             // GIF
+    b"GIF89a",             // GIF
+    b"PK\x03\x04",         // ZIP, and so also JAR, DOCX, EPUB, etc.
+    b"PK\x05\x06",         // Empty ZIP
+    b"\x1f\x8b",           // Gzip
+    b"\x28\xb5\x2f\xfd",   // Zstandard
+    b"BZh",                // Bzip2
+    b"\xfd7zXZ\x00",       // XZ
+    b"7z\xbc\xaf\x27\x1c", // 7-Zip
+    b"Rar!\x1a\x07",       // RAR
+    b"%PDF-",              // PDF
+    b"wOFF",               // WOFF
+    b"wOF2",               // WOFF2
+    b"OggS",               // Ogg
+    b"ID3",                // MP3
+];
+
+/// What happens to responses without a `Content-Type`, see [`CompressMiddlewareBuilder::missing_content_type`](crate::CompressMiddlewareBuilder::missing_content_type).
+///
+/// Tide labels bodies set without a MIME type `application/octet-stream`, such as those from bytes or readers,
+/// so unless compressing them all with `Always`, responses labelled `application/octet-stream` count as missing a `Content-Type` too.
+/// `Always` leaves them to the usual `Content-Type` checks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MissingContentType {
+    /// Compress them. The default.
+    #[default]
+    Always,
+    /// Never compress them.
+    Never,
+    /// Sniff the first bytes of the body, as much as is available at first, and compress them unless they look binary.
+    ///
+    /// Only sniffed once an encoding other than `identity` is negotiated.
+    ///
+    /// Bodies starting with the signature of a compressed or binary format, such as PNG, JPEG, GIF, WebP, ZIP, Gzip, or PDF,
+    /// or containing control characters which do not occur in text, are not compressed.
+    Sniff,
+}
+
+/// Whether the start of a body, as much as is available at first, looks like text.
+///
+/// Does not wait for more than the first bytes, and leaves them buffered in the body.
+pub(crate) async fn is_text(body: &mut Body) -> io::Result<bool> {
+    Ok(looks_like_text(body.fill_buf().await?))
+}
+
+/// Whether the bytes look like text, as opposed to a binary format.
+fn looks_like_text(prefix: &[u8]) -> bool {
+    let binary_format = MAGIC.iter().any(|magic| prefix.starts_with(magic))
+        // WebP: a RIFF container of WEBP data.
+        || (prefix.starts_with(b"RIFF") && prefix.get(8..12) == Some(b"WEBP"))
+        // ISO base media, e.g. MP4, MOV, AVIF, and HEIC.
+        || prefix.get(4..8) == Some(b"ftyp");
+    // The binary data bytes of the WHATWG MIME Sniffing Standard.
+    let binary_byte = prefix
+        .iter()
+        .any(|byte| matches!(byte, 0x00..=0x08 | 0x0b | 0x0e..=0x1a | 0x1c..=0x1f));
+    !binary_format && !binary_byte
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "brotli")]
+
+use async_compression::futures::bufread::BrotliDecoder;
+use async_compression::Level;
+use futures_lite::io::{AsyncReadExt, BufReader};
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+use tide_compress::{BrotliMode, BrotliParams};
+
+const JSON: &str = r#"{"chunks":[{"name":"one","data":"data data"},{"name":"two","data":"data data"},{"name":"three","data":"data data"}]}"#;
+
+fn app_with(brotli_params: BrotliParams) -> tide::Server<()> {
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .brotli_quality(Level::Best)
+            .brotli_params(brotli_params)
+            .build(),
+    );
+    app.at("/json").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(JSON);
+        res.set_content_type("application/json");
+        Ok(res)
+    });
+    app.at("/font").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(JSON.as_bytes());
+        res.set_content_type("font/ttf");
+        Ok(res)
+    });
+    app
+}
+
+async fn compressed(app: &tide::Server<()>, path: &str) -> Vec<u8> {
+    let mut req = Request::new(
+        Method::Get,
+        Url::parse("http://_/").unwrap().join(path).unwrap(),
+    );
+    req.insert_header(headers::ACCEPT_ENCODING, "br");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+    assert_eq!(res.status(), 200);
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+    res.body_bytes().await.unwrap()
+}
+
+async fn decompress(compressed: &[u8]) -> Vec<u8> {
+    let mut decompressed = vec![];
+    BrotliDecoder::new(BufReader::new(compressed))
+        .read_to_end(&mut decompressed)
+        .await
+        .unwrap();
+    decompressed
+}
+
+#[async_std::test]
+async fn window_size() {
+    let app = app_with(BrotliParams::new().window(18));
+    let body = compressed(&app, "/json").await;
+
+    // The stream header starts with the window size: a set bit, then `18 - 17` in three bits.
+    assert_eq!(body[0] & 0b1111, 0b0011);
+    assert_eq!(decompress(&body).await, JSON.as_bytes());
+
+    // Values beyond the range are clamped.
+    assert_eq!(BrotliParams::new().window(30).window, Some(24));
+    assert_eq!(BrotliParams::new().window(1).window, Some(10));
+}
+
+#[async_std::test]
+async fn mode_from_content_type() {
+    let app = app_with(
+        BrotliParams::new()
+            .mode(BrotliMode::FromContentType)
+            .size_hint(true),
+    );
+
+    let json = compressed(&app, "/json").await;
+    assert_eq!(decompress(&json).await, JSON.as_bytes());
+
+    let font = compressed(&app, "/font").await;
+    assert_eq!(decompress(&font).await, JSON.as_bytes());
+}
+
+#[async_std::test]
+async fn default_params_unchanged() {
+    let default = compressed(&app_with(BrotliParams::default()), "/json").await;
+    let generic = compressed(
+        &app_with(BrotliParams::new().mode(BrotliMode::Generic)),
+        "/json",
+    )
+    .await;
+    assert_eq!(default, generic);
+    assert_eq!(decompress(&default).await, JSON.as_bytes());
+}
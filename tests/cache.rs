@@ -0,0 +1,222 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+const BR_COMPRESSED: &[u8] = &[
+    139, 31, 128, 67, 104, 117, 110, 107, 32, 111, 110, 101, 10, 100, 97, 116, 97, 32, 100, 97,
+    116, 97, 10, 10, 67, 104, 117, 110, 107, 32, 116, 119, 111, 10, 100, 97, 116, 97, 32, 100, 97,
+    116, 97, 10, 10, 67, 104, 117, 110, 107, 32, 116, 104, 114, 101, 101, 10, 100, 97, 116, 97, 32,
+    100, 97, 116, 97, 10, 3,
+];
+
+fn request(path: &str) -> Request {
+    let mut req = Request::new(
+        Method::Get,
+        Url::parse("http://_/").unwrap().join(path).unwrap(),
+    );
+    req.insert_header(headers::ACCEPT_ENCODING, "br");
+    req
+}
+
+#[async_std::test]
+async fn cache_hit() {
+    let cache = tide_compress::CompressionCache::new(1024);
+
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .cache(Some(cache.clone()))
+            .build(),
+    );
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.insert_header(headers::ETAG, "\"abc\"");
+        Ok(res)
+    });
+
+    for _ in 0..3 {
+        let mut res: tide::http::Response = app.respond(request("/")).await.unwrap();
+
+        assert_eq!(res.status(), 200);
+        assert_eq!(res[headers::CONTENT_ENCODING], "br");
+        assert_eq!(res[headers::VARY], "accept-encoding");
+        assert_eq!(res.body_bytes().await.unwrap(), BR_COMPRESSED);
+    }
+
+    assert_eq!(cache.misses(), 1);
+    assert_eq!(cache.hits(), 2);
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.size(), BR_COMPRESSED.len());
+}
+
+#[async_std::test]
+async fn cache_etag_changed() {
+    let cache = tide_compress::CompressionCache::new(1024);
+    let version = Arc::new(AtomicUsize::new(0));
+
+    let mut app = tide::with_state(version.clone());
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .cache(Some(cache.clone()))
+            .build(),
+    );
+    app.at("/")
+        .get(|req: tide::Request<Arc<AtomicUsize>>| async move {
+            let version = req.state().load(Ordering::SeqCst);
+            let mut res = Response::new(StatusCode::Ok);
+            res.set_body(format!("{}{}", TEXT, version));
+            res.insert_header(headers::ETAG, format!("\"v{}\"", version));
+            Ok(res)
+        });
+
+    let mut res: tide::http::Response = app.respond(request("/")).await.unwrap();
+    let first = res.body_bytes().await.unwrap();
+
+    version.store(1, Ordering::SeqCst);
+
+    let mut res: tide::http::Response = app.respond(request("/")).await.unwrap();
+    let second = res.body_bytes().await.unwrap();
+
+    assert_ne!(first, second);
+    assert_eq!(cache.misses(), 2);
+    assert_eq!(cache.hits(), 0);
+    // The entry for the previous ETag has been dropped.
+    assert_eq!(cache.len(), 1);
+}
+
+#[async_std::test]
+async fn cache_query() {
+    let cache = tide_compress::CompressionCache::new(1024);
+
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .cache(Some(cache.clone()))
+            .build(),
+    );
+    app.at("/report").get(|req: tide::Request<()>| async move {
+        let id = req.url().query().unwrap_or_default().to_owned();
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(format!("{}{}", TEXT, id));
+        res.insert_header(headers::ETAG, format!("\"{}\"", id));
+        Ok(res)
+    });
+
+    for _ in 0..2 {
+        for path in ["/report?id=1", "/report?id=2"] {
+            let res: tide::http::Response = app.respond(request(path)).await.unwrap();
+            assert_eq!(res[headers::CONTENT_ENCODING], "br");
+        }
+    }
+
+    // Each query has its own entry, rather than replacing the other's.
+    assert_eq!(cache.misses(), 2);
+    assert_eq!(cache.hits(), 2);
+    assert_eq!(cache.len(), 2);
+}
+
+#[async_std::test]
+async fn cache_level_policy() {
+    let cache = tide_compress::CompressionCache::new(1024);
+
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .cache(Some(cache.clone()))
+            .level_policy(Arc::new(
+                tide_compress::SizeLevelPolicy::new().busy(4, async_compression::Level::Fastest),
+            ))
+            .build(),
+    );
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.insert_header(headers::ETAG, "\"abc\"");
+        Ok(res)
+    });
+
+    let res: tide::http::Response = app.respond(request("/")).await.unwrap();
+
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+    assert_eq!(cache.misses(), 0);
+    assert!(cache.is_empty());
+}
+
+#[async_std::test]
+async fn cache_weak_etag() {
+    let cache = tide_compress::CompressionCache::new(1024);
+
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .cache(Some(cache.clone()))
+            .build(),
+    );
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.insert_header(headers::ETAG, "W/\"abc\"");
+        Ok(res)
+    });
+
+    let mut res: tide::http::Response = app.respond(request("/")).await.unwrap();
+
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+    assert_eq!(res.body_bytes().await.unwrap(), BR_COMPRESSED);
+    assert_eq!(cache.misses(), 0);
+    assert!(cache.is_empty());
+}
+
+#[async_std::test]
+async fn cache_eviction() {
+    // Only room for one entry.
+    let cache = tide_compress::CompressionCache::new(BR_COMPRESSED.len() + 16);
+
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .cache(Some(cache.clone()))
+            .build(),
+    );
+    app.at("/*").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.insert_header(headers::ETAG, "\"abc\"");
+        Ok(res)
+    });
+
+    app.respond::<_, tide::http::Response>(request("/one"))
+        .await
+        .unwrap();
+    app.respond::<_, tide::http::Response>(request("/two"))
+        .await
+        .unwrap();
+    app.respond::<_, tide::http::Response>(request("/one"))
+        .await
+        .unwrap();
+
+    assert_eq!(cache.misses(), 3);
+    assert_eq!(cache.hits(), 0);
+    assert_eq!(cache.len(), 1);
+    assert!(cache.size() <= cache.max_size());
+}
@@ -99,9 +99,12 @@ async fn gzip_compressed() {
 
 #[cfg(feature = "deflate")]
 const DEFLATED: &[u8] = &[
+    0x78, 0x9c, // zlib header
     0x6d, 0xca, 0xb1, 0x09, 0x00, 0x30, 0x08, 0x05, 0xd1, 0xfe, 0x4f, 0xe1, 0x2e, 0x4e, 0x22, 0x44,
     0x10, 0x02, 0x0a, 0xc1, 0x90, 0xf5, 0x43, 0x52, 0x59, 0xd8, 0x5c, 0xf1, 0x38, 0xb6, 0xed, 0x93,
     0xc2, 0x15, 0x43, 0x52, 0xe8, 0x05, 0xe0, 0x8f, 0x79, 0xa2, 0x41, 0x5b, 0x5a, 0xdf, 0x0b,
+    // adler32
+    0xba, 0x21, 0x15, 0x4c,
 ];
 
 #[cfg(feature = "deflate")]
@@ -129,3 +132,36 @@ async fn deflate_compressed() {
     assert_eq!(res[headers::VARY], "accept-encoding");
     assert_eq!(res.body_bytes().await.unwrap(), DEFLATED);
 }
+
+#[cfg(feature = "zstd")]
+const ZSTD_COMPRESSED: &[u8] = &[
+    40, 181, 47, 253, 0, 88, 53, 1, 0, 216, 67, 104, 117, 110, 107, 32, 111, 110, 101, 10, 100, 97,
+    116, 97, 32, 100, 97, 116, 97, 10, 10, 116, 119, 111, 104, 114, 101, 3, 0, 79, 113, 3, 164, 17,
+    135, 201, 4,
+];
+
+#[cfg(feature = "zstd")]
+#[async_std::test]
+async fn zstd_compressed() {
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        Ok(res)
+    });
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "zstd");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert!(res.header(headers::CONTENT_LENGTH).is_none());
+    assert_eq!(res[headers::CONTENT_ENCODING], "zstd");
+    assert_eq!(res[headers::VARY], "accept-encoding");
+    assert_eq!(res.body_bytes().await.unwrap(), ZSTD_COMPRESSED);
+}
@@ -0,0 +1,158 @@
+// The compressions counted as in flight are Brotli.
+#![cfg(feature = "brotli")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[cfg(feature = "gzip")]
+use async_compression::futures::bufread::GzipDecoder;
+use async_std::task;
+#[cfg(feature = "gzip")]
+use futures_lite::io::{AsyncReadExt, BufReader};
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+use tide_compress::{CompressionObserver, OverloadFallback, SkipReason};
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+const REQUESTS: usize = 16;
+const MAX_IN_FLIGHT: usize = 4;
+
+#[derive(Debug, Default)]
+struct Recorder {
+    overloaded: AtomicUsize,
+    skipped_overloaded: AtomicUsize,
+}
+
+impl CompressionObserver for Recorder {
+    fn overloaded(&self) {
+        self.overloaded.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn skipped(&self, reason: SkipReason) {
+        if reason == SkipReason::Overloaded {
+            self.skipped_overloaded.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+fn app_with(middleware: tide_compress::CompressMiddleware) -> Arc<tide::Server<()>> {
+    let mut app = tide::new();
+    app.with(middleware);
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        Ok(res)
+    });
+    Arc::new(app)
+}
+
+/// Sends the requests concurrently, holding on to the responses and so their in-flight bodies.
+async fn concurrent_requests(app: &Arc<tide::Server<()>>) -> Vec<tide::http::Response> {
+    let handles: Vec<_> = (0..REQUESTS)
+        .map(|_| {
+            let app = Arc::clone(app);
+            task::spawn(async move {
+                let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+                req.insert_header(headers::ACCEPT_ENCODING, "br, gzip");
+                let res: tide::http::Response = app.respond(req).await.unwrap();
+                assert_eq!(res.status(), 200);
+                res
+            })
+        })
+        .collect();
+    let mut responses = vec![];
+    for handle in handles {
+        responses.push(handle.await);
+    }
+    responses
+}
+
+fn count_encoding(responses: &[tide::http::Response], encoding: Option<&str>) -> usize {
+    responses
+        .iter()
+        .filter(|res| res.header(headers::CONTENT_ENCODING).map(|v| v.as_str()) == encoding)
+        .count()
+}
+
+#[cfg(feature = "gzip")]
+#[async_std::test]
+async fn cheaper_fallback() {
+    let recorder = Arc::new(Recorder::default());
+    let middleware = tide_compress::CompressMiddleware::builder()
+        .threshold(16)
+        .max_in_flight(Some(MAX_IN_FLIGHT))
+        .observer(recorder.clone())
+        .build();
+    let app = app_with(middleware.clone());
+
+    let mut responses = concurrent_requests(&app).await;
+    assert_eq!(middleware.in_flight(), MAX_IN_FLIGHT);
+    assert_eq!(count_encoding(&responses, Some("br")), MAX_IN_FLIGHT);
+    assert_eq!(
+        count_encoding(&responses, Some("gzip")),
+        REQUESTS - MAX_IN_FLIGHT
+    );
+    assert_eq!(
+        recorder.overloaded.load(Ordering::Relaxed),
+        REQUESTS - MAX_IN_FLIGHT
+    );
+
+    // The fallback bodies are still valid, and not counted as in flight.
+    let res = responses
+        .iter_mut()
+        .find(|res| res[headers::CONTENT_ENCODING] == "gzip")
+        .unwrap();
+    let mut decoded = String::new();
+    GzipDecoder::new(BufReader::new(res.take_body()))
+        .read_to_string(&mut decoded)
+        .await
+        .unwrap();
+    assert_eq!(decoded, TEXT);
+    assert_eq!(middleware.in_flight(), MAX_IN_FLIGHT);
+
+    drop(responses);
+    assert_eq!(middleware.in_flight(), 0);
+
+    // Once the bodies are gone, compression is back to normal.
+    let responses = concurrent_requests(&app).await;
+    assert_eq!(count_encoding(&responses, Some("br")), MAX_IN_FLIGHT);
+}
+
+#[async_std::test]
+async fn identity_fallback() {
+    let recorder = Arc::new(Recorder::default());
+    let middleware = tide_compress::CompressMiddleware::builder()
+        .threshold(16)
+        .max_in_flight(Some(MAX_IN_FLIGHT))
+        .overload_fallback(OverloadFallback::Identity)
+        .observer(recorder.clone())
+        .build();
+    let app = app_with(middleware.clone());
+
+    let mut responses = concurrent_requests(&app).await;
+    assert_eq!(count_encoding(&responses, Some("br")), MAX_IN_FLIGHT);
+    assert_eq!(count_encoding(&responses, None), REQUESTS - MAX_IN_FLIGHT);
+    assert_eq!(
+        recorder.skipped_overloaded.load(Ordering::Relaxed),
+        REQUESTS - MAX_IN_FLIGHT
+    );
+
+    let res = responses
+        .iter_mut()
+        .find(|res| res.header(headers::CONTENT_ENCODING).is_none())
+        .unwrap();
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+
+    drop(responses);
+    assert_eq!(middleware.in_flight(), 0);
+}
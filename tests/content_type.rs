@@ -51,6 +51,7 @@ async fn raw_bytes_uncompressed_default() {
     assert_eq!(res.body_string().await.unwrap(), TEXT);
 }
 
+#[cfg(feature = "regex-check")]
 #[async_std::test]
 async fn raw_bytes_compressed_no_filter() {
     let mut app = tide::new();
@@ -103,3 +104,238 @@ async fn compressible_content_type() {
     assert_eq!(res[headers::VARY], "accept-encoding");
     assert_eq!(res.body_bytes().await.unwrap(), BR_COMPRESSED);
 }
+
+#[cfg(all(feature = "db-check", feature = "regex-check"))]
+#[async_std::test]
+async fn incompressible_content_type() {
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .content_type_check(Some(regex::Regex::new(".*").unwrap()))
+            .build(),
+    );
+    app.at("/:content_type")
+        .get(|req: tide::Request<()>| async move {
+            let content_type = req.param("content_type")?.replace('_', "/");
+            let mut res = Response::new(StatusCode::Ok);
+            res.set_body(TEXT.as_bytes().to_owned());
+            res.set_content_type(content_type.as_str());
+            Ok(res)
+        });
+
+    // Known to be incompressible, so rejected whatever the regular expression.
+    for path in ["/image_png", "/application_zip"] {
+        let url = Url::parse("http://_/").unwrap().join(path).unwrap();
+        let mut req = Request::new(Method::Get, url);
+        req.insert_header(headers::ACCEPT_ENCODING, "br");
+        let res: tide::http::Response = app.respond(req).await.unwrap();
+        assert_eq!(res.status(), 200);
+        assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    }
+
+    // Not known either way, so left to the regular expression.
+    let url = Url::parse("http://_/application_x-abiword").unwrap();
+    let mut req = Request::new(Method::Get, url);
+    req.insert_header(headers::ACCEPT_ENCODING, "br");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+}
+
+#[cfg(all(feature = "db-check", not(feature = "regex-check")))]
+#[async_std::test]
+async fn database_without_regex() {
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+    app.at("/:content_type")
+        .get(|req: tide::Request<()>| async move {
+            let content_type = req.param("content_type")?.replace('_', "/");
+            let mut res = Response::new(StatusCode::Ok);
+            res.set_body(TEXT.as_bytes().to_owned());
+            res.set_content_type(content_type.as_str());
+            Ok(res)
+        });
+
+    for (path, compressed) in [
+        // Known to be incompressible.
+        ("/image_png", false),
+        ("/application_zip", false),
+        // Known to be compressible.
+        ("/application_json", true),
+        // Not known either way, and there is nothing else to check.
+        ("/application_x-abiword", true),
+    ] {
+        let url = Url::parse("http://_/").unwrap().join(path).unwrap();
+        let mut req = Request::new(Method::Get, url);
+        req.insert_header(headers::ACCEPT_ENCODING, "br");
+        let res: tide::http::Response = app.respond(req).await.unwrap();
+        assert_eq!(res.status(), 200);
+        assert_eq!(
+            res.header(headers::CONTENT_ENCODING).is_some(),
+            compressed,
+            "{}",
+            path
+        );
+    }
+}
+
+#[async_std::test]
+async fn allow_and_deny_lists() {
+    let builder = tide_compress::CompressMiddleware::builder()
+        .threshold(16)
+        .allow_content_type("application/vnd.ourco.*")
+        .allow_content_type("image/svg+xml")
+        .deny_content_type("application/vnd.ourco.archive")
+        .deny_content_type("image/*")
+        .deny_content_type("Text/CSV");
+    #[cfg(not(feature = "regex-check"))]
+    let builder = builder.content_type_matcher(Some(tide_compress::ContentTypeMatcher::standard()));
+    let mut app = tide::new();
+    app.with(builder.build());
+    app.at("/:content_type")
+        .get(|req: tide::Request<()>| async move {
+            let content_type = req.param("content_type")?.replace('_', "/");
+            let mut res = Response::new(StatusCode::Ok);
+            res.set_body(TEXT.as_bytes().to_owned());
+            res.set_content_type(content_type.as_str());
+            Ok(res)
+        });
+
+    for (path, compressed) in [
+        // Allowed by pattern, though the regex does not match.
+        ("/application_vnd.ourco.report", true),
+        // The exact deny entry takes precedence over the allow pattern.
+        ("/application_vnd.ourco.archive", false),
+        // The exact allow entry takes precedence over the deny pattern.
+        ("/image_svg+xml", true),
+        ("/image_bmp", false),
+        // Deny entries take precedence over the MIME database & the regex, case insensitively.
+        ("/text_csv", false),
+        // Anything else falls through to the MIME database & the regex.
+        ("/text_plain", true),
+        ("/application_vnd.otherco.report", false),
+    ] {
+        let url = Url::parse("http://_/").unwrap().join(path).unwrap();
+        let mut req = Request::new(Method::Get, url);
+        req.insert_header(headers::ACCEPT_ENCODING, "br");
+        let res: tide::http::Response = app.respond(req).await.unwrap();
+        assert_eq!(res.status(), 200);
+        assert_eq!(
+            res.header(headers::CONTENT_ENCODING).is_some(),
+            compressed,
+            "{}",
+            path
+        );
+    }
+}
+
+#[async_std::test]
+async fn deny_list_without_regex() {
+    let builder = tide_compress::CompressMiddleware::builder()
+        .threshold(16)
+        .deny_content_type("application/octet-stream");
+    #[cfg(feature = "regex-check")]
+    let builder = builder.content_type_check(None);
+    let mut app = tide::new();
+    app.with(builder.build());
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.as_bytes().to_owned());
+        Ok(res)
+    });
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "br");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+}
+
+#[async_std::test]
+async fn matcher_without_regex() {
+    let builder = tide_compress::CompressMiddleware::builder()
+        .threshold(16)
+        .content_type_matcher(Some(
+            tide_compress::ContentTypeMatcher::new()
+                .exact("application/x-abiword")
+                .prefix("application/vnd.ourco.")
+                .suffix("+report"),
+        ));
+    #[cfg(feature = "regex-check")]
+    let builder = builder.content_type_check(None);
+    let mut app = tide::new();
+    app.with(builder.build());
+    app.at("/:content_type")
+        .get(|req: tide::Request<()>| async move {
+            let content_type = req.param("content_type")?.replace('_', "/");
+            let mut res = Response::new(StatusCode::Ok);
+            res.set_body(TEXT.as_bytes().to_owned());
+            res.insert_header(headers::CONTENT_TYPE, content_type.as_str());
+            Ok(res)
+        });
+
+    for (path, compressed) in [
+        ("/application_x-abiword", true),
+        // Parameters are ignored, and matching is case insensitive.
+        ("/Application_X-Abiword;%20charset=utf-8", true),
+        ("/application_vnd.ourco.anything", true),
+        ("/application_sales+report", true),
+        ("/application_x-other", false),
+    ] {
+        let url = Url::parse("http://_/").unwrap().join(path).unwrap();
+        let mut req = Request::new(Method::Get, url);
+        req.insert_header(headers::ACCEPT_ENCODING, "br");
+        let res: tide::http::Response = app.respond(req).await.unwrap();
+        assert_eq!(res.status(), 200);
+        assert_eq!(
+            res.header(headers::CONTENT_ENCODING).is_some(),
+            compressed,
+            "{}",
+            path
+        );
+    }
+}
+
+#[test]
+fn standard_matcher() {
+    let matcher = tide_compress::ContentTypeMatcher::standard();
+    assert!(matcher.is_match("text/html"));
+    assert!(matcher.is_match("application/ld+json"));
+    assert!(matcher.is_match("Image/SVG+XML"));
+    assert!(!matcher.is_match("application/octet-stream"));
+    assert!(!tide_compress::ContentTypeMatcher::new().is_match("text/html"));
+}
+
+#[async_std::test]
+async fn unparsable_content_type() {
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.as_bytes().to_owned());
+        res.insert_header(headers::CONTENT_TYPE, "text");
+        Ok(res)
+    });
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "br");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    // Matches nothing, rather than failing the response.
+    assert_eq!(res.status(), 200);
+    assert_eq!(res[headers::CONTENT_TYPE], "text");
+    assert_eq!(
+        res.header(headers::CONTENT_ENCODING).is_none(),
+        cfg!(feature = "regex-check")
+    );
+    if res.header(headers::CONTENT_ENCODING).is_none() {
+        assert_eq!(res.body_string().await.unwrap(), TEXT);
+    }
+}
@@ -0,0 +1,127 @@
+// The decisions include those of the MIME database and the regular expression.
+#![cfg(all(feature = "db-check", feature = "regex-check"))]
+
+use tide::http::content::Encoding;
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+use tide_compress::{
+    CompressionDecision, CompressionOutcome, ContentTypeRule, DebugDecision, SkipReason,
+    DECISION_HEADER,
+};
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+fn app_with(middleware: tide_compress::CompressMiddleware) -> tide::Server<()> {
+    let mut app = tide::new();
+    app.with(middleware);
+    app.at("/:content_type")
+        .get(|req: tide::Request<()>| async move {
+            let content_type = req.param("content_type")?.replace('_', "/");
+            let mut res = Response::new(StatusCode::Ok);
+            res.set_body(TEXT.to_owned());
+            res.set_content_type(content_type.as_str());
+            Ok(res)
+        });
+    app
+}
+
+async fn request(app: &tide::Server<()>, path: &str) -> tide::http::Response {
+    let url = Url::parse("http://_/").unwrap().join(path).unwrap();
+    let mut req = Request::new(Method::Get, url);
+    req.insert_header(headers::ACCEPT_ENCODING, "br");
+    app.respond(req).await.unwrap()
+}
+
+#[async_std::test]
+async fn decision_extension() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .debug_decision(DebugDecision::Extension)
+            .build(),
+    );
+
+    let res = request(&app, "/text_plain").await;
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+    assert_eq!(
+        res.ext().get::<CompressionDecision>(),
+        Some(&CompressionDecision {
+            outcome: CompressionOutcome::Compressed(Encoding::Brotli),
+            threshold: 16,
+            content_type_rule: Some(ContentTypeRule::Database),
+        })
+    );
+    assert!(res.header(DECISION_HEADER).is_none());
+
+    let res = request(&app, "/image_png").await;
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(
+        res.ext().get::<CompressionDecision>(),
+        Some(&CompressionDecision {
+            outcome: CompressionOutcome::Skipped(SkipReason::ContentType),
+            threshold: 16,
+            content_type_rule: Some(ContentTypeRule::Incompressible),
+        })
+    );
+
+    let res = request(&app, "/application_x-abiword").await;
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(
+        res.ext().get::<CompressionDecision>(),
+        Some(&CompressionDecision {
+            outcome: CompressionOutcome::Skipped(SkipReason::ContentType),
+            threshold: 16,
+            content_type_rule: Some(ContentTypeRule::Unmatched),
+        })
+    );
+}
+
+#[async_std::test]
+async fn decision_header() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .debug_decision(DebugDecision::Header)
+            .build(),
+    );
+
+    let res = request(&app, "/text_x-custom").await;
+    assert_eq!(
+        res[DECISION_HEADER],
+        "compressed=br; threshold=16; content-type=regex"
+    );
+
+    let url = Url::parse("http://_/text_plain").unwrap();
+    let res: tide::http::Response = app.respond(Request::new(Method::Get, url)).await.unwrap();
+    assert_eq!(
+        res[DECISION_HEADER],
+        "skipped=no-accept-encoding; threshold=16"
+    );
+    assert_eq!(
+        res.ext().get::<CompressionDecision>().unwrap().outcome,
+        CompressionOutcome::Skipped(SkipReason::NoAcceptEncoding)
+    );
+}
+
+#[async_std::test]
+async fn decision_off_by_default() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+
+    let res = request(&app, "/text_plain").await;
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+    assert!(res.ext().get::<CompressionDecision>().is_none());
+    assert!(res.header(DECISION_HEADER).is_none());
+}
@@ -0,0 +1,292 @@
+#[cfg(feature = "gzip")]
+use async_compression::futures::bufread::GzipEncoder;
+#[cfg(feature = "gzip")]
+use futures_lite::AsyncReadExt;
+use tide::http::{headers, Method, Request, StatusCode, Url};
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+#[cfg(feature = "brotli")]
+const BR_COMPRESSED: &[u8] = &[
+    139, 31, 128, 67, 104, 117, 110, 107, 32, 111, 110, 101, 10, 100, 97, 116, 97, 32, 100, 97,
+    116, 97, 10, 10, 67, 104, 117, 110, 107, 32, 116, 119, 111, 10, 100, 97, 116, 97, 32, 100, 97,
+    116, 97, 10, 10, 67, 104, 117, 110, 107, 32, 116, 104, 114, 101, 101, 10, 100, 97, 116, 97, 32,
+    100, 97, 116, 97, 10, 3,
+];
+
+#[cfg(feature = "gzip")]
+const GZIPPED: &[u8] = &[
+    0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // gzip header
+    0xff, // OS type
+    0x6d, 0xca, 0xb1, 0x09, 0x00, 0x30, 0x08, 0x05, 0xd1, 0xfe, 0x4f, 0xe1, 0x2e, 0x4e, 0x22, 0x44,
+    0x10, 0x02, 0x0a, 0xc1, 0x90, 0xf5, 0x43, 0x52, 0x59, 0xd8, 0x5c, 0xf1, 0x38, 0xb6, 0xed, 0x93,
+    0xc2, 0x15, 0x43, 0x52, 0xe8, 0x05, 0xe0, 0x8f, 0x79, 0xa2, 0x41, 0x5b, 0x5a, 0xdf, 0x0b, 0xde,
+    0xf2, 0xd7, 0x81, // crc32
+    0x40, 0x00, 0x00, 0x00, // input size
+];
+
+fn echo_app() -> tide::Server<()> {
+    echo_app_with(tide_compress::DecompressMiddleware::new())
+}
+
+fn echo_app_with(middleware: tide_compress::DecompressMiddleware) -> tide::Server<()> {
+    let mut app = tide::new();
+    app.with(middleware);
+    app.at("/").post(|mut req: tide::Request<()>| async move {
+        let mut res = tide::Response::new(StatusCode::Ok);
+        if req.header(headers::CONTENT_ENCODING).is_some() {
+            res.insert_header("X-Had-Content-Encoding", "true");
+        }
+        if req.header(headers::CONTENT_LENGTH).is_some() {
+            res.insert_header("X-Had-Content-Length", "true");
+        }
+        res.set_body(req.body_string().await?);
+        Ok(res)
+    });
+    app
+}
+
+#[cfg(feature = "brotli")]
+#[async_std::test]
+async fn brotli_decompressed() {
+    let app = echo_app();
+
+    let mut req = Request::new(Method::Post, Url::parse("http://_/").unwrap());
+    req.set_body(BR_COMPRESSED);
+    req.insert_header(headers::CONTENT_ENCODING, "br");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert!(res.header("X-Had-Content-Encoding").is_none());
+    assert!(res.header("X-Had-Content-Length").is_none());
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+}
+
+#[cfg(feature = "gzip")]
+#[async_std::test]
+async fn gzip_decompressed() {
+    let app = echo_app();
+
+    let mut req = Request::new(Method::Post, Url::parse("http://_/").unwrap());
+    req.set_body(GZIPPED);
+    req.insert_header(headers::CONTENT_ENCODING, "gzip");
+    req.insert_header(headers::CONTENT_LENGTH, GZIPPED.len().to_string());
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert!(res.header("X-Had-Content-Encoding").is_none());
+    assert!(res.header("X-Had-Content-Length").is_none());
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+}
+
+#[cfg(feature = "zstd")]
+const ZSTD_COMPRESSED: &[u8] = &[
+    40, 181, 47, 253, 0, 88, 53, 1, 0, 216, 67, 104, 117, 110, 107, 32, 111, 110, 101, 10, 100, 97,
+    116, 97, 32, 100, 97, 116, 97, 10, 10, 116, 119, 111, 104, 114, 101, 3, 0, 79, 113, 3, 164, 17,
+    135, 201, 4,
+];
+
+#[cfg(feature = "zstd")]
+#[async_std::test]
+async fn zstd_decompressed() {
+    let app = echo_app();
+
+    let mut req = Request::new(Method::Post, Url::parse("http://_/").unwrap());
+    req.set_body(ZSTD_COMPRESSED);
+    req.insert_header(headers::CONTENT_ENCODING, "zstd");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert!(res.header("X-Had-Content-Encoding").is_none());
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+}
+
+#[cfg(feature = "deflate")]
+const DEFLATED: &[u8] = &[
+    0x78, 0xda, // zlib header
+    0x6d, 0xca, 0xb1, 0x09, 0x00, 0x30, 0x08, 0x05, 0xd1, 0xfe, 0x4f, 0xe1, 0x2e, 0x4e, 0x22, 0x44,
+    0x10, 0x02, 0x0a, 0xc1, 0x90, 0xf5, 0x43, 0x52, 0x59, 0xd8, 0x5c, 0xf1, 0x38, 0xb6, 0xed, 0x93,
+    0xc2, 0x15, 0x43, 0x52, 0xe8, 0x05, 0xe0, 0x8f, 0x79, 0xa2, 0x41, 0x5b, 0x5a, 0xdf, 0x0b, 0xba,
+    0x21, 0x15, 0x4c, // adler32
+];
+
+#[cfg(feature = "deflate")]
+const RAW_DEFLATED: &[u8] = &[
+    0x6d, 0xca, 0xb1, 0x09, 0x00, 0x30, 0x08, 0x05, 0xd1, 0xfe, 0x4f, 0xe1, 0x2e, 0x4e, 0x22, 0x44,
+    0x10, 0x02, 0x0a, 0xc1, 0x90, 0xf5, 0x43, 0x52, 0x59, 0xd8, 0x5c, 0xf1, 0x38, 0xb6, 0xed, 0x93,
+    0xc2, 0x15, 0x43, 0x52, 0xe8, 0x05, 0xe0, 0x8f, 0x79, 0xa2, 0x41, 0x5b, 0x5a, 0xdf, 0x0b,
+];
+
+#[cfg(feature = "deflate")]
+#[async_std::test]
+async fn deflate_decompressed() {
+    let app = echo_app();
+
+    let mut req = Request::new(Method::Post, Url::parse("http://_/").unwrap());
+    req.set_body(DEFLATED);
+    req.insert_header(headers::CONTENT_ENCODING, "deflate");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert!(res.header("X-Had-Content-Encoding").is_none());
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+}
+
+#[cfg(feature = "deflate")]
+#[async_std::test]
+async fn raw_deflate_decompressed() {
+    let app = echo_app();
+
+    let mut req = Request::new(Method::Post, Url::parse("http://_/").unwrap());
+    req.set_body(RAW_DEFLATED);
+    req.insert_header(headers::CONTENT_ENCODING, "deflate");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert!(res.header("X-Had-Content-Encoding").is_none());
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+}
+
+#[async_std::test]
+async fn identity_passthrough() {
+    let app = echo_app();
+
+    let mut req = Request::new(Method::Post, Url::parse("http://_/").unwrap());
+    req.set_body(TEXT);
+    req.insert_header(headers::CONTENT_ENCODING, "identity");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert!(res.header("X-Had-Content-Encoding").is_none());
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+}
+
+#[async_std::test]
+async fn no_content_encoding() {
+    let app = echo_app();
+
+    let mut req = Request::new(Method::Post, Url::parse("http://_/").unwrap());
+    req.set_body(TEXT);
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+}
+
+#[cfg(any(
+    feature = "brotli",
+    feature = "gzip",
+    feature = "deflate",
+    feature = "zstd"
+))]
+#[async_std::test]
+async fn unsupported_content_encoding() {
+    let app = echo_app();
+
+    let mut req = Request::new(Method::Post, Url::parse("http://_/").unwrap());
+    req.set_body(TEXT);
+    req.insert_header(headers::CONTENT_ENCODING, "compress");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::UnsupportedMediaType);
+    assert!(res.header(headers::ACCEPT_ENCODING).is_some());
+}
+
+#[cfg(feature = "gzip")]
+/// A gzip payload of 1 MiB of zeros, which compresses to roughly 1 KiB.
+async fn gzip_bomb() -> Vec<u8> {
+    let zeros = vec![0; 1024 * 1024];
+    let mut compressed = vec![];
+    GzipEncoder::new(zeros.as_slice())
+        .read_to_end(&mut compressed)
+        .await
+        .unwrap();
+    compressed
+}
+
+#[cfg(feature = "gzip")]
+#[async_std::test]
+async fn max_size_exceeded() {
+    let app = echo_app_with(
+        tide_compress::DecompressMiddleware::builder()
+            .max_size(Some(64 * 1024))
+            .build(),
+    );
+
+    let mut req = Request::new(Method::Post, Url::parse("http://_/").unwrap());
+    req.set_body(gzip_bomb().await);
+    req.insert_header(headers::CONTENT_ENCODING, "gzip");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::PayloadTooLarge);
+}
+
+#[cfg(feature = "gzip")]
+#[async_std::test]
+async fn max_ratio_exceeded() {
+    let app = echo_app_with(
+        tide_compress::DecompressMiddleware::builder()
+            .max_size(None)
+            .max_ratio(Some(100))
+            .build(),
+    );
+
+    let mut req = Request::new(Method::Post, Url::parse("http://_/").unwrap());
+    req.set_body(gzip_bomb().await);
+    req.insert_header(headers::CONTENT_ENCODING, "gzip");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::PayloadTooLarge);
+}
+
+#[cfg(feature = "gzip")]
+#[async_std::test]
+async fn stacked_encodings_max_size_exceeded() {
+    let app = echo_app_with(
+        tide_compress::DecompressMiddleware::builder()
+            .max_size(Some(64 * 1024))
+            .build(),
+    );
+
+    let bomb = gzip_bomb().await;
+    let mut compressed = vec![];
+    GzipEncoder::new(bomb.as_slice())
+        .read_to_end(&mut compressed)
+        .await
+        .unwrap();
+
+    let mut req = Request::new(Method::Post, Url::parse("http://_/").unwrap());
+    req.set_body(compressed);
+    req.insert_header(headers::CONTENT_ENCODING, "gzip, gzip");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::PayloadTooLarge);
+}
+
+#[cfg(feature = "gzip")]
+#[async_std::test]
+async fn within_limits() {
+    let app = echo_app_with(
+        tide_compress::DecompressMiddleware::builder()
+            .max_size(Some(2 * 1024 * 1024))
+            .max_ratio(Some(2000))
+            .build(),
+    );
+
+    let mut req = Request::new(Method::Post, Url::parse("http://_/").unwrap());
+    req.set_body(gzip_bomb().await);
+    req.insert_header(headers::CONTENT_ENCODING, "gzip");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert_eq!(res.body_bytes().await.unwrap().len(), 1024 * 1024);
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "deflate")]
+
+use async_compression::futures::bufread::{DeflateDecoder, ZlibDecoder};
+use futures_lite::io::{AsyncRead, AsyncReadExt, BufReader};
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+use tide_compress::DeflateFormat;
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+async fn deflated(deflate_format: DeflateFormat) -> Vec<u8> {
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .deflate_format(deflate_format)
+            .build(),
+    );
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        Ok(res)
+    });
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "deflate");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+    assert_eq!(res.status(), 200);
+    assert_eq!(res[headers::CONTENT_ENCODING], "deflate");
+    res.body_bytes().await.unwrap()
+}
+
+async fn decode(mut decoder: impl AsyncRead + Unpin) -> std::io::Result<String> {
+    let mut decoded = String::new();
+    decoder.read_to_string(&mut decoded).await?;
+    Ok(decoded)
+}
+
+#[async_std::test]
+async fn zlib_by_default() {
+    let body = deflated(DeflateFormat::default()).await;
+
+    // CMF: deflate with a 32K window, and FCHECK making the header a multiple of 31.
+    assert_eq!(body[0], 0x78);
+    assert_eq!(u16::from_be_bytes([body[0], body[1]]) % 31, 0);
+
+    let decoded = decode(ZlibDecoder::new(BufReader::new(&body[..]))).await;
+    assert_eq!(decoded.unwrap(), TEXT);
+}
+
+#[async_std::test]
+async fn raw() {
+    let body = deflated(DeflateFormat::Raw).await;
+
+    let decoded = decode(DeflateDecoder::new(BufReader::new(&body[..]))).await;
+    assert_eq!(decoded.unwrap(), TEXT);
+
+    // Not zlib-wrapped.
+    assert!(decode(ZlibDecoder::new(BufReader::new(&body[..])))
+        .await
+        .is_err());
+}
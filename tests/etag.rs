@@ -0,0 +1,116 @@
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+fn app_with(etag_mode: Option<tide_compress::ETagMode>) -> tide::Server<()> {
+    let mut builder = tide_compress::CompressMiddleware::builder().threshold(16);
+    if let Some(etag_mode) = etag_mode {
+        builder = builder.etag_mode(etag_mode);
+    }
+
+    let mut app = tide::new();
+    app.with(builder.build());
+    app.at("/").get(|req: tide::Request<()>| async move {
+        // A naive handler which matches If-None-Match exactly.
+        if let Some(if_none_match) = req.header(headers::IF_NONE_MATCH) {
+            if if_none_match.as_str() == "\"abc\"" {
+                let mut res = Response::new(StatusCode::NotModified);
+                res.insert_header(headers::ETAG, "\"abc\"");
+                return Ok(res);
+            }
+        }
+
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.insert_header(headers::ETAG, "\"abc\"");
+        Ok(res)
+    });
+    app
+}
+
+#[async_std::test]
+async fn etag_weakened_by_default() {
+    let app = app_with(None);
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "br");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+    assert_eq!(res[headers::ETAG], "W/\"abc\"");
+}
+
+#[async_std::test]
+async fn etag_uncompressed_untouched() {
+    let app = app_with(None);
+
+    let req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(res[headers::ETAG], "\"abc\"");
+}
+
+#[async_std::test]
+async fn etag_keep() {
+    let app = app_with(Some(tide_compress::ETagMode::Keep));
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "br");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+    assert_eq!(res[headers::ETAG], "\"abc\"");
+}
+
+#[async_std::test]
+async fn etag_suffix() {
+    let app = app_with(Some(tide_compress::ETagMode::Suffix));
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "gzip");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+    assert_eq!(res[headers::ETAG], "\"abc-gzip\"");
+}
+
+#[async_std::test]
+async fn etag_suffix_not_modified() {
+    let app = app_with(Some(tide_compress::ETagMode::Suffix));
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "br");
+    req.insert_header(headers::IF_NONE_MATCH, "\"abc-br\"");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NotModified);
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(res[headers::ETAG], "\"abc-br\"");
+}
+
+#[async_std::test]
+async fn etag_suffix_modified() {
+    let app = app_with(Some(tide_compress::ETagMode::Suffix));
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "br");
+    req.insert_header(headers::IF_NONE_MATCH, "\"xyz-br\"");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+    assert_eq!(res[headers::ETAG], "\"abc-br\"");
+}
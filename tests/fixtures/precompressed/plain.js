@@ -0,0 +1,8 @@
+Chunk one
+data data
+
+Chunk two
+data data
+
+Chunk three
+data data
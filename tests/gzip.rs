@@ -0,0 +1,112 @@
+#![cfg(feature = "gzip")]
+
+use async_compression::futures::bufread::GzipDecoder;
+use async_compression::Level;
+use futures_lite::io::{AsyncReadExt, BufReader};
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+use tide_compress::GzipHeader;
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+const GZIPPED_FASTEST: &[u8] = &[
+    0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, // gzip header.
+    0xff, // OS type
+    109, 202, 177, 9, 0, 48, 8, 5, 209, 254, 79, 225, 46, //
+    153, 68, 136, 32, 4, 20, 130, 33, 235, 135, 96, 99, 97, 115, 197, 227, 134, 30, 91, 228, 38,
+    152, 28, 76, 63, 64, 98, 92, 111, 80, 183, 212, 247, 1, //
+    0xde, 0xf2, 0xd7, 0x81, // crc32
+    0x40, 0x00, 0x00, 0x00, // input size
+];
+
+#[cfg(feature = "deflate")]
+const DEFLATED_BEST: &[u8] = &[
+    120, 218, // zlib header
+    109, 202, 177, 9, 0, 48, 8, 5, 209, 254, 79, 225, 46, //
+    78, 34, 68, 16, 2, 10, 193, 144, 245, 67, 82, 89, 216, 92, 241, 56, 182, 237, 147, 194, 21, 67,
+    82, 232, 5, 224, 143, 121, 162, 65, 91, 90, 223, 11, //
+    186, 33, 21, 76, // adler32
+];
+
+fn app_with(middleware: tide_compress::CompressMiddleware) -> tide::Server<()> {
+    let mut app = tide::new();
+    app.with(middleware);
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        Ok(res)
+    });
+    app
+}
+
+async fn compressed(app: &tide::Server<()>, accept_encoding: &str) -> Vec<u8> {
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, accept_encoding);
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+    assert_eq!(res.status(), 200);
+    assert_eq!(res[headers::CONTENT_ENCODING], accept_encoding);
+    res.body_bytes().await.unwrap()
+}
+
+#[async_std::test]
+async fn independent_qualities() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .deflate_quality(Level::Best)
+            .gzip_quality(Level::Fastest)
+            .build(),
+    );
+
+    assert_eq!(compressed(&app, "gzip").await, GZIPPED_FASTEST);
+    #[cfg(feature = "deflate")]
+    assert_eq!(compressed(&app, "deflate").await, DEFLATED_BEST);
+
+    // In either order.
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .gzip_quality(Level::Fastest)
+            .deflate_quality(Level::Best)
+            .build(),
+    );
+
+    assert_eq!(compressed(&app, "gzip").await, GZIPPED_FASTEST);
+    #[cfg(feature = "deflate")]
+    assert_eq!(compressed(&app, "deflate").await, DEFLATED_BEST);
+}
+
+#[async_std::test]
+async fn header_fields() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .gzip_quality(Level::Fastest)
+            .gzip_header(GzipHeader::new().mtime(0x6553_f100).os(GzipHeader::UNIX))
+            .build(),
+    );
+
+    let body = compressed(&app, "gzip").await;
+    assert_eq!(body[4..8], [0x00, 0xf1, 0x53, 0x65]);
+    assert_eq!(body[9], GzipHeader::UNIX);
+    // Everything else is untouched.
+    assert_eq!(body[..4], GZIPPED_FASTEST[..4]);
+    assert_eq!(body[8], GZIPPED_FASTEST[8]);
+    assert_eq!(body[10..], GZIPPED_FASTEST[10..]);
+
+    let mut decompressed = String::new();
+    GzipDecoder::new(BufReader::new(&body[..]))
+        .read_to_string(&mut decompressed)
+        .await
+        .unwrap();
+    assert_eq!(decompressed, TEXT);
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "brotli")]
+
+use std::sync::{Arc, Mutex};
+
+use async_compression::Level;
+use tide::http::content::Encoding;
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+use tide_compress::{LevelContext, LevelPolicy, SizeLevelPolicy};
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+const BR_COMPRESSED_BEST: &[u8] = &[
+    27, 63, 0, 248, 157, 9, 118, 12, 101, 50, 101, 248, 252, 26, 229, 16, 90, 93, 43, 144, 189,
+    209, 105, 5, 16, 55, 58, 200, 132, 35, 141, 117, 16, 5, 199, 247, 22, 131, 0, 51, 145, 60, 128,
+    132, 79, 166, 110, 169, 162, 169, 129, 224, 63, 191, 0,
+];
+
+const BR_COMPRESSED_FASTEST: &[u8] = &[
+    139, 31, 128, 67, 104, 117, 110, 107, 32, 111, 110, 101, 10, 100, 97, 116, 97, 32, 100, 97,
+    116, 97, 10, 10, 67, 104, 117, 110, 107, 32, 116, 119, 111, 10, 100, 97, 116, 97, 32, 100, 97,
+    116, 97, 10, 10, 67, 104, 117, 110, 107, 32, 116, 104, 114, 101, 101, 10, 100, 97, 116, 97, 32,
+    100, 97, 116, 97, 10, 3,
+];
+
+fn app_with(middleware: tide_compress::CompressMiddleware) -> tide::Server<()> {
+    let mut app = tide::new();
+    app.with(middleware);
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        Ok(res)
+    });
+    app
+}
+
+async fn request(app: &tide::Server<()>) -> tide::http::Response {
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "br");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+    assert_eq!(res.status(), 200);
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+    res
+}
+
+#[async_std::test]
+async fn size_policy() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .level_policy(Arc::new(SizeLevelPolicy::new().size(1024, Level::Best)))
+            .build(),
+    );
+
+    let mut res = request(&app).await;
+    assert_eq!(res.body_bytes().await.unwrap(), BR_COMPRESSED_BEST);
+}
+
+#[async_std::test]
+async fn size_policy_fallback() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .brotli_quality(Level::Best)
+            .level_policy(Arc::new(
+                SizeLevelPolicy::new()
+                    .size(16, Level::Best)
+                    .fallback(Level::Fastest),
+            ))
+            .build(),
+    );
+
+    let mut res = request(&app).await;
+    assert_eq!(res.body_bytes().await.unwrap(), BR_COMPRESSED_FASTEST);
+}
+
+#[derive(Debug, Default)]
+struct Recorder(Mutex<Vec<(Encoding, LevelContext)>>);
+
+impl LevelPolicy for Recorder {
+    fn level(&self, encoding: Encoding, context: LevelContext) -> Option<Level> {
+        self.0.lock().unwrap().push((encoding, context));
+        None
+    }
+}
+
+#[async_std::test]
+async fn in_flight_context() {
+    let recorder = Arc::new(Recorder::default());
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .level_policy(recorder.clone())
+            .build(),
+    );
+
+    let first = request(&app).await;
+    let mut second = request(&app).await;
+    assert_eq!(second.body_bytes().await.unwrap(), BR_COMPRESSED_FASTEST);
+    drop(first);
+    drop(second);
+    let _third = request(&app).await;
+
+    let contexts: Vec<(Encoding, Option<usize>, usize)> = recorder
+        .0
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(encoding, context)| (*encoding, context.body_len, context.in_flight))
+        .collect();
+    assert_eq!(
+        contexts,
+        [
+            (Encoding::Brotli, Some(TEXT.len()), 0),
+            (Encoding::Brotli, Some(TEXT.len()), 1),
+            (Encoding::Brotli, Some(TEXT.len()), 0),
+        ]
+    );
+}
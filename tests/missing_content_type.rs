@@ -0,0 +1,200 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_lite::io::{AsyncRead, BufReader};
+use tide::http::{headers, Body, Method, Request, StatusCode, Url};
+use tide::Response;
+use tide_compress::{CompressionDecision, ContentTypeRule, DebugDecision, MissingContentType};
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+const PNG: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, // PNG signature
+    0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+    0x08, 0x06, 0x00, 0x00, 0x00, 0x1f, 0x15, 0xc4, 0x89,
+];
+
+fn app_with(missing_content_type: MissingContentType) -> tide::Server<()> {
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .missing_content_type(missing_content_type)
+            .debug_decision(DebugDecision::Extension)
+            .build(),
+    );
+    app.at("/text").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT);
+        res.remove_header(headers::CONTENT_TYPE);
+        Ok(res)
+    });
+    app.at("/stream").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(Body::from_reader(BufReader::new(Stalling(false)), None));
+        res.remove_header(headers::CONTENT_TYPE);
+        Ok(res)
+    });
+    app.at("/png").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(PNG);
+        res.remove_header(headers::CONTENT_TYPE);
+        Ok(res)
+    });
+    // Labelled `application/octet-stream` by tide.
+    app.at("/bytes").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.as_bytes());
+        Ok(res)
+    });
+    app.at("/png_bytes").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(PNG);
+        Ok(res)
+    });
+    app
+}
+
+/// A streamed body which sends its first chunk, and then nothing more.
+struct Stalling(bool);
+
+impl AsyncRead for Stalling {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        if self.0 {
+            return Poll::Pending;
+        }
+        self.0 = true;
+        let chunk = TEXT.as_bytes();
+        buf[..chunk.len()].copy_from_slice(chunk);
+        Poll::Ready(Ok(chunk.len()))
+    }
+}
+
+async fn request(app: &tide::Server<()>, path: &str) -> tide::http::Response {
+    request_accepting(app, path, "gzip").await
+}
+
+async fn request_accepting(
+    app: &tide::Server<()>,
+    path: &str,
+    accept_encoding: &str,
+) -> tide::http::Response {
+    let url = Url::parse("http://_/").unwrap().join(path).unwrap();
+    let mut req = Request::new(Method::Get, url);
+    req.insert_header(headers::ACCEPT_ENCODING, accept_encoding);
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+    assert_eq!(res.status(), 200);
+    res
+}
+
+fn content_type_rule(res: &tide::http::Response) -> Option<ContentTypeRule> {
+    res.ext()
+        .get::<CompressionDecision>()
+        .unwrap()
+        .content_type_rule
+}
+
+#[async_std::test]
+async fn always_by_default() {
+    let app = app_with(MissingContentType::default());
+
+    for path in ["/text", "/png"] {
+        let res = request(&app, path).await;
+        assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+        assert_eq!(content_type_rule(&res), None);
+    }
+}
+
+#[async_std::test]
+async fn never() {
+    let app = app_with(MissingContentType::Never);
+
+    let mut res = request(&app, "/text").await;
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(content_type_rule(&res), Some(ContentTypeRule::Missing));
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+}
+
+#[async_std::test]
+async fn sniff() {
+    let app = app_with(MissingContentType::Sniff);
+
+    let res = request(&app, "/text").await;
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+    assert_eq!(content_type_rule(&res), Some(ContentTypeRule::SniffedText));
+
+    // The sniffed bytes are put back, and no `Content-Type` is added.
+    let mut res = request(&app, "/png").await;
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert!(res.header(headers::CONTENT_TYPE).is_none());
+    assert_eq!(
+        content_type_rule(&res),
+        Some(ContentTypeRule::SniffedBinary)
+    );
+    assert_eq!(res.len(), Some(PNG.len()));
+    assert_eq!(res.body_bytes().await.unwrap(), PNG);
+}
+
+#[async_std::test]
+async fn sniff_only_first_bytes() {
+    let app = app_with(MissingContentType::Sniff);
+
+    // Decided from the first chunk, without waiting for more of the body.
+    let res = async_std::future::timeout(Duration::from_secs(5), request(&app, "/stream"))
+        .await
+        .expect("the response should not wait for more of the body");
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+    assert_eq!(content_type_rule(&res), Some(ContentTypeRule::SniffedText));
+}
+
+#[async_std::test]
+async fn sniff_only_when_compressing() {
+    let app = app_with(MissingContentType::Sniff);
+
+    let res = request_accepting(&app, "/png", "identity").await;
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(content_type_rule(&res), None);
+}
+
+#[async_std::test]
+async fn default_label_counts_as_missing() {
+    let app = app_with(MissingContentType::Sniff);
+
+    let res = request(&app, "/bytes").await;
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+    assert_eq!(res[headers::CONTENT_TYPE], "application/octet-stream");
+    assert_eq!(content_type_rule(&res), Some(ContentTypeRule::SniffedText));
+
+    let res = request(&app, "/png_bytes").await;
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(res[headers::CONTENT_TYPE], "application/octet-stream");
+    assert_eq!(
+        content_type_rule(&res),
+        Some(ContentTypeRule::SniffedBinary)
+    );
+
+    let app = app_with(MissingContentType::Never);
+    let res = request(&app, "/bytes").await;
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(content_type_rule(&res), Some(ContentTypeRule::Missing));
+
+    // Left to the usual `Content-Type` checks.
+    let app = app_with(MissingContentType::Always);
+    let res = request(&app, "/bytes").await;
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_ne!(content_type_rule(&res), Some(ContentTypeRule::Missing));
+}
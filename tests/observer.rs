@@ -0,0 +1,156 @@
+use std::sync::{Arc, Mutex};
+
+use tide::http::content::Encoding;
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+use tide_compress::{CompressionObserver, EncoderStats, SkipReason};
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+const BR_COMPRESSED: &[u8] = &[
+    139, 31, 128, 67, 104, 117, 110, 107, 32, 111, 110, 101, 10, 100, 97, 116, 97, 32, 100, 97,
+    116, 97, 10, 10, 67, 104, 117, 110, 107, 32, 116, 119, 111, 10, 100, 97, 116, 97, 32, 100, 97,
+    116, 97, 10, 10, 67, 104, 117, 110, 107, 32, 116, 104, 114, 101, 101, 10, 100, 97, 116, 97, 32,
+    100, 97, 116, 97, 10, 3,
+];
+
+#[derive(Debug, Default)]
+struct Recorder {
+    compressed: Mutex<Vec<Encoding>>,
+    skipped: Mutex<Vec<SkipReason>>,
+    encoded: Mutex<Vec<(Encoding, EncoderStats)>>,
+}
+
+impl CompressionObserver for Recorder {
+    fn compressed(&self, encoding: Encoding) {
+        self.compressed.lock().unwrap().push(encoding);
+    }
+
+    fn skipped(&self, reason: SkipReason) {
+        self.skipped.lock().unwrap().push(reason);
+    }
+
+    fn encoded(&self, encoding: Encoding, stats: EncoderStats) {
+        self.encoded.lock().unwrap().push((encoding, stats));
+    }
+}
+
+fn app_with(recorder: Arc<Recorder>) -> tide::Server<()> {
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .observer(recorder)
+            .build(),
+    );
+    app.at("/").all(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        Ok(res)
+    });
+    app.at("/short").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body("short");
+        Ok(res)
+    });
+    app.at("/image").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.set_content_type("image/png");
+        Ok(res)
+    });
+    app.at("/no-transform").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.insert_header(headers::CACHE_CONTROL, "no-transform");
+        Ok(res)
+    });
+    app.at("/encoded").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.insert_header(headers::CONTENT_ENCODING, "gzip");
+        Ok(res)
+    });
+    app
+}
+
+fn request(method: Method, path: &str, accept_encoding: Option<&str>) -> Request {
+    let mut req = Request::new(method, Url::parse("http://_/").unwrap().join(path).unwrap());
+    if let Some(accept_encoding) = accept_encoding {
+        req.insert_header(headers::ACCEPT_ENCODING, accept_encoding);
+    }
+    req
+}
+
+#[async_std::test]
+async fn compressed_stats() {
+    let recorder = Arc::new(Recorder::default());
+    let app = app_with(recorder.clone());
+
+    let mut res: tide::http::Response = app
+        .respond(request(Method::Get, "/", Some("br")))
+        .await
+        .unwrap();
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+    assert_eq!(res.body_bytes().await.unwrap(), BR_COMPRESSED);
+    drop(res);
+
+    assert_eq!(*recorder.compressed.lock().unwrap(), [Encoding::Brotli]);
+    assert!(recorder.skipped.lock().unwrap().is_empty());
+
+    let encoded = recorder.encoded.lock().unwrap();
+    assert_eq!(encoded.len(), 1);
+    let (encoding, stats) = encoded[0];
+    assert_eq!(encoding, Encoding::Brotli);
+    assert_eq!(stats.bytes_in, TEXT.len() as u64);
+    assert_eq!(stats.bytes_out, BR_COMPRESSED.len() as u64);
+}
+
+#[async_std::test]
+async fn skip_reasons() {
+    let recorder = Arc::new(Recorder::default());
+    let app = app_with(recorder.clone());
+
+    for (method, path, accept_encoding) in [
+        (Method::Head, "/", Some("br")),
+        (Method::Get, "/", None),
+        (Method::Get, "/no-transform", Some("br")),
+        (Method::Get, "/encoded", Some("br")),
+        (Method::Get, "/short", Some("br")),
+        (Method::Get, "/image", Some("br")),
+        (Method::Get, "/", Some("identity")),
+    ] {
+        let res: tide::http::Response = app
+            .respond(request(method, path, accept_encoding))
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        if path != "/encoded" {
+            assert!(res.header(headers::CONTENT_ENCODING).is_none());
+        }
+    }
+
+    assert_eq!(
+        *recorder.skipped.lock().unwrap(),
+        [
+            SkipReason::Head,
+            SkipReason::NoAcceptEncoding,
+            SkipReason::NoTransform,
+            SkipReason::AlreadyEncoded,
+            SkipReason::BelowThreshold,
+            SkipReason::ContentType,
+            SkipReason::Identity,
+        ]
+    );
+    assert!(recorder.compressed.lock().unwrap().is_empty());
+    assert!(recorder.encoded.lock().unwrap().is_empty());
+}
@@ -0,0 +1,169 @@
+use tide::http::content::Encoding;
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+use tide_compress::CompressionOverride;
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+#[cfg(feature = "brotli")]
+const BR_COMPRESSED: &[u8] = &[
+    27, 63, 0, 248, 157, 9, 118, 12, 101, 50, 101, 248, 252, 26, 229, 16, 90, 93, 43, 144, 189,
+    209, 105, 5, 16, 55, 58, 200, 132, 35, 141, 117, 16, 5, 199, 247, 22, 131, 0, 51, 145, 60, 128,
+    132, 79, 166, 110, 169, 162, 169, 129, 224, 63, 191, 0,
+];
+
+fn request(accept_encoding: &str) -> Request {
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, accept_encoding);
+    req
+}
+
+#[async_std::test]
+async fn disabled_by_response() {
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.insert_ext(CompressionOverride::new().enabled(false));
+        Ok(res)
+    });
+
+    let mut res: tide::http::Response = app.respond(request("br")).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert!(res.header(headers::VARY).is_none());
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+}
+
+#[async_std::test]
+async fn disabled_by_request() {
+    let mut app = tide::new();
+    app.with(tide::utils::Before(|mut req: tide::Request<()>| async {
+        req.set_ext(CompressionOverride::new().enabled(false));
+        req
+    }));
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        Ok(res)
+    });
+
+    let mut res: tide::http::Response = app.respond(request("br")).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+}
+
+#[async_std::test]
+async fn response_takes_precedence_over_request() {
+    let mut app = tide::new();
+    app.with(tide::utils::Before(|mut req: tide::Request<()>| async {
+        req.set_ext(CompressionOverride::new().enabled(false));
+        req
+    }));
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.insert_ext(CompressionOverride::new().enabled(true));
+        Ok(res)
+    });
+
+    let res: tide::http::Response = app.respond(request("br")).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+}
+
+#[async_std::test]
+async fn forced_below_threshold_and_content_type() {
+    let mut app = tide::new();
+    app.with(tide_compress::CompressMiddleware::new());
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.as_bytes());
+        res.set_content_type("image/png");
+        res.insert_ext(CompressionOverride::new().enabled(true));
+        Ok(res)
+    });
+
+    let res: tide::http::Response = app.respond(request("gzip")).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert!(res.header(headers::CONTENT_LENGTH).is_none());
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+    assert_eq!(res[headers::VARY], "accept-encoding");
+}
+
+#[async_std::test]
+async fn restricted_encodings() {
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.insert_ext(CompressionOverride::new().encodings([Encoding::Gzip]));
+        Ok(res)
+    });
+
+    let res: tide::http::Response = app.respond(request("br, gzip")).await.unwrap();
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+
+    // Nothing acceptable remains, so the body is left uncompressed.
+    let mut res: tide::http::Response = app.respond(request("br")).await.unwrap();
+    assert_eq!(res.status(), 200);
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+}
+
+#[cfg(feature = "brotli")]
+#[async_std::test]
+async fn level() {
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.insert_ext(CompressionOverride::new().level(async_compression::Level::Best));
+        Ok(res)
+    });
+
+    let mut res: tide::http::Response = app.respond(request("br")).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+    assert_eq!(res.body_bytes().await.unwrap(), BR_COMPRESSED);
+}
@@ -0,0 +1,130 @@
+use tide::http::{headers, Method, Request, StatusCode, Url};
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+const BR_COMPRESSED: &[u8] = &[
+    139, 31, 128, 67, 104, 117, 110, 107, 32, 111, 110, 101, 10, 100, 97, 116, 97, 32, 100, 97,
+    116, 97, 10, 10, 67, 104, 117, 110, 107, 32, 116, 119, 111, 10, 100, 97, 116, 97, 32, 100, 97,
+    116, 97, 10, 10, 67, 104, 117, 110, 107, 32, 116, 104, 114, 101, 101, 10, 100, 97, 116, 97, 32,
+    100, 97, 116, 97, 10, 3,
+];
+
+const GZIPPED: &[u8] = &[
+    0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // gzip header
+    0xff, // OS type
+    0x6d, 0xca, 0xb1, 0x09, 0x00, 0x30, 0x08, 0x05, 0xd1, 0xfe, 0x4f, 0xe1, 0x2e, 0x4e, 0x22, 0x44,
+    0x10, 0x02, 0x0a, 0xc1, 0x90, 0xf5, 0x43, 0x52, 0x59, 0xd8, 0x5c, 0xf1, 0x38, 0xb6, 0xed, 0x93,
+    0xc2, 0x15, 0x43, 0x52, 0xe8, 0x05, 0xe0, 0x8f, 0x79, 0xa2, 0x41, 0x5b, 0x5a, 0xdf, 0x0b, 0xde,
+    0xf2, 0xd7, 0x81, // crc32
+    0x40, 0x00, 0x00, 0x00, // input size
+];
+
+fn static_app() -> tide::Server<()> {
+    let mut app = tide::new();
+    app.at("/static/*").get(
+        tide_compress::ServeDirCompressed::new("/static/", "tests/fixtures/precompressed").unwrap(),
+    );
+    app
+}
+
+#[async_std::test]
+async fn brotli_sibling() {
+    let app = static_app();
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/static/app.js").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "gzip, br");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+    assert!(res[headers::CONTENT_TYPE]
+        .as_str()
+        .starts_with("application/javascript"));
+    assert_eq!(res[headers::VARY], "accept-encoding");
+    assert_eq!(res.body_bytes().await.unwrap(), BR_COMPRESSED);
+}
+
+#[async_std::test]
+async fn gzip_sibling() {
+    let app = static_app();
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/static/app.js").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "gzip");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+    assert!(res[headers::CONTENT_TYPE]
+        .as_str()
+        .starts_with("application/javascript"));
+    assert_eq!(res[headers::VARY], "accept-encoding");
+    assert_eq!(res.body_bytes().await.unwrap(), GZIPPED);
+}
+
+#[async_std::test]
+async fn next_best_sibling() {
+    let app = static_app();
+
+    // There is no `.zst` sibling.
+    let mut req = Request::new(Method::Get, Url::parse("http://_/static/app.js").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "zstd, gzip;q=0.5");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+    assert_eq!(res.body_bytes().await.unwrap(), GZIPPED);
+}
+
+#[async_std::test]
+async fn no_accepts_encoding() {
+    let app = static_app();
+
+    let req = Request::new(Method::Get, Url::parse("http://_/static/app.js").unwrap());
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+}
+
+#[async_std::test]
+async fn no_sibling_compressed_on_the_fly() {
+    let mut app = static_app();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/static/plain.js").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "br");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+    assert_eq!(res[headers::VARY], "accept-encoding");
+    assert_eq!(res.body_bytes().await.unwrap(), BR_COMPRESSED);
+}
+
+#[async_std::test]
+async fn not_found() {
+    let app = static_app();
+
+    let mut req = Request::new(
+        Method::Get,
+        Url::parse("http://_/static/missing.js").unwrap(),
+    );
+    req.insert_header(headers::ACCEPT_ENCODING, "br");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NotFound);
+}
@@ -0,0 +1,89 @@
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+fn app_with(middleware: tide_compress::CompressMiddleware) -> tide::Server<()> {
+    let mut app = tide::new();
+    app.with(middleware);
+    app.at("/*").all(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        Ok(res)
+    });
+    app
+}
+
+fn request(method: Method, path: &str) -> Request {
+    let mut req = Request::new(method, Url::parse("http://_/").unwrap().join(path).unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "gzip");
+    req
+}
+
+#[async_std::test]
+async fn request_path_and_headers() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .should_compress(|req, _res| {
+                !req.path().starts_with("/api/") || req.header(headers::AUTHORIZATION).is_some()
+            })
+            .build(),
+    );
+
+    let res: tide::http::Response = app.respond(request(Method::Get, "/index")).await.unwrap();
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+
+    let mut res: tide::http::Response = app
+        .respond(request(Method::Get, "/api/users"))
+        .await
+        .unwrap();
+    assert_eq!(res.status(), 200);
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+
+    let mut req = request(Method::Get, "/api/users");
+    req.insert_header(headers::AUTHORIZATION, "Bearer token");
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+}
+
+#[async_std::test]
+async fn request_method_and_response() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .should_compress(|req, res| {
+                req.method() == Method::Post && res.header("X-Compress").is_none()
+            })
+            .build(),
+    );
+
+    let res: tide::http::Response = app.respond(request(Method::Get, "/index")).await.unwrap();
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+
+    let res: tide::http::Response = app.respond(request(Method::Post, "/index")).await.unwrap();
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+}
+
+#[async_std::test]
+async fn usual_checks_still_apply() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .should_compress(|_req, _res| true)
+            .build(),
+    );
+
+    // Below the default threshold.
+    let res: tide::http::Response = app.respond(request(Method::Get, "/index")).await.unwrap();
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+}
@@ -0,0 +1,129 @@
+use tide::http::content::Encoding;
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+fn app_with(middleware: tide_compress::CompressMiddleware) -> tide::Server<()> {
+    let mut app = tide::new();
+    app.with(middleware);
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        Ok(res)
+    });
+    app
+}
+
+async fn content_encoding(app: &tide::Server<()>, accept_encoding: &str) -> Option<String> {
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, accept_encoding);
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+    assert_eq!(res.status(), 200);
+    res.header(headers::CONTENT_ENCODING)
+        .map(|value| value.as_str().to_owned())
+}
+
+#[async_std::test]
+async fn default_preference() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+
+    assert_eq!(content_encoding(&app, "gzip, br").await.unwrap(), "br");
+    assert_eq!(content_encoding(&app, "*").await.unwrap(), "br");
+}
+
+#[async_std::test]
+async fn server_preference_breaks_ties() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .encodings([Encoding::Gzip, Encoding::Brotli])
+            .build(),
+    );
+
+    assert_eq!(content_encoding(&app, "br, gzip").await.unwrap(), "gzip");
+    assert_eq!(
+        content_encoding(&app, "br;q=0.8, gzip;q=0.8")
+            .await
+            .unwrap(),
+        "gzip"
+    );
+    assert_eq!(content_encoding(&app, "*").await.unwrap(), "gzip");
+}
+
+#[async_std::test]
+async fn client_weights_take_precedence() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .encodings([Encoding::Gzip, Encoding::Brotli])
+            .build(),
+    );
+
+    assert_eq!(
+        content_encoding(&app, "br;q=1.0, gzip;q=0.5")
+            .await
+            .unwrap(),
+        "br"
+    );
+    assert_eq!(content_encoding(&app, "gzip;q=0, br").await.unwrap(), "br");
+}
+
+#[async_std::test]
+async fn disabled_encodings() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .encodings([Encoding::Gzip])
+            .build(),
+    );
+
+    assert_eq!(content_encoding(&app, "br, gzip").await.unwrap(), "gzip");
+    assert_eq!(content_encoding(&app, "br, identity").await, None);
+    // Identity is acceptable unless excluded.
+    assert_eq!(content_encoding(&app, "br").await, None);
+
+    for accept_encoding in ["br, identity;q=0", "br, *;q=0"] {
+        let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+        req.insert_header(headers::ACCEPT_ENCODING, accept_encoding);
+        let res: tide::http::Response = app.respond(req).await.unwrap();
+        assert_eq!(
+            res.status(),
+            StatusCode::NotAcceptable,
+            "{}",
+            accept_encoding
+        );
+    }
+}
+
+#[async_std::test]
+async fn identity_only_as_fallback() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .encodings([Encoding::Gzip])
+            .build(),
+    );
+
+    // Not listed, so identity is not preferred over a lower weighted encoding.
+    assert_eq!(content_encoding(&app, "gzip;q=0.5").await.unwrap(), "gzip");
+    // Weighted by the wildcard.
+    assert_eq!(content_encoding(&app, "gzip;q=0.5, *;q=0.8").await, None);
+    assert_eq!(
+        content_encoding(&app, "gzip;q=0.5, *;q=0").await.unwrap(),
+        "gzip"
+    );
+}
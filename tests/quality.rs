@@ -101,11 +101,13 @@ async fn gzip_compressed_quality_fastest() {
 
 #[cfg(feature = "deflate")]
 const DEFLATED: &[u8] = &[
+    120, 218, // zlib header
     // Deflate header?
     109, 202, 177, 9, 0, 48, 8, 5, 209, 254, 79, 225, 46, //
     // Deflate data
     78, 34, 68, 16, 2, 10, 193, 144, 245, 67, 82, 89, 216, 92, 241, 56, 182, 237, 147, 194, 21, 67,
-    82, 232, 5, 224, 143, 121, 162, 65, 91, 90, 223, 11,
+    82, 232, 5, 224, 143, 121, 162, 65, 91, 90, 223, 11, //
+    186, 33, 21, 76, // adler32
 ];
 
 #[cfg(feature = "deflate")]
@@ -134,3 +136,37 @@ async fn deflate_compressed_quality_best() {
     assert_eq!(res[headers::VARY], "accept-encoding");
     assert_eq!(res.body_bytes().await.unwrap(), DEFLATED);
 }
+
+#[cfg(feature = "zstd")]
+const ZSTD_COMPRESSED: &[u8] = &[
+    40, 181, 47, 253, 0, 128, 61, 1, 0, 224, 67, 104, 117, 110, 107, 32, 111, 110, 101, 10, 100,
+    97, 116, 97, 32, 100, 97, 116, 97, 10, 10, 116, 119, 111, 104, 114, 101, 101, 3, 0, 58, 13, 2,
+    210, 136, 195, 100, 2,
+];
+
+#[cfg(feature = "zstd")]
+#[async_std::test]
+async fn zstd_compressed_quality_best() {
+    let mut app = tide::new();
+    app.with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .zstd_quality(async_compression::Level::Best)
+            .build(),
+    );
+    app.at("/").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        Ok(res)
+    });
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "zstd");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    assert_eq!(res.status(), 200);
+    assert!(res.header(headers::CONTENT_LENGTH).is_none());
+    assert_eq!(res[headers::CONTENT_ENCODING], "zstd");
+    assert_eq!(res[headers::VARY], "accept-encoding");
+    assert_eq!(res.body_bytes().await.unwrap(), ZSTD_COMPRESSED);
+}
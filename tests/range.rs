@@ -0,0 +1,109 @@
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+const ENCODINGS: &[&str] = &[
+    #[cfg(feature = "brotli")]
+    "br",
+    #[cfg(feature = "zstd")]
+    "zstd",
+    #[cfg(feature = "gzip")]
+    "gzip",
+    #[cfg(feature = "deflate")]
+    "deflate",
+];
+
+/// A handler which honors `Range: bytes=0-15` and otherwise serves the whole body.
+fn app_with(middleware: tide_compress::CompressMiddleware) -> tide::Server<()> {
+    let mut app = tide::new();
+    app.with(middleware);
+    app.at("/").get(|req: tide::Request<()>| async move {
+        match req.header("Range") {
+            Some(range) if range.as_str() == "bytes=0-15" => {
+                let mut res = Response::new(StatusCode::PartialContent);
+                res.set_body(TEXT[0..16].to_owned());
+                res.insert_header(headers::CONTENT_RANGE, format!("bytes 0-15/{}", TEXT.len()));
+                res.insert_header(headers::ACCEPT_RANGES, "bytes");
+                Ok(res)
+            }
+            _ => {
+                let mut res = Response::new(StatusCode::Ok);
+                res.set_body(TEXT.to_owned());
+                res.insert_header(headers::ACCEPT_RANGES, "bytes");
+                Ok(res)
+            }
+        }
+    });
+    app
+}
+
+#[async_std::test]
+async fn partial_content_uncompressed() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(8)
+            .build(),
+    );
+
+    for encoding in ENCODINGS {
+        let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+        req.insert_header(headers::ACCEPT_ENCODING, *encoding);
+        req.insert_header("Range", "bytes=0-15");
+        let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+        assert_eq!(res.status(), StatusCode::PartialContent);
+        assert!(res.header(headers::CONTENT_ENCODING).is_none());
+        assert_eq!(res[headers::ACCEPT_RANGES], "bytes");
+        assert_eq!(res.body_string().await.unwrap(), &TEXT[0..16]);
+    }
+}
+
+#[async_std::test]
+async fn ignored_range_compressed() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+
+    for encoding in ENCODINGS {
+        let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+        req.insert_header(headers::ACCEPT_ENCODING, *encoding);
+        req.insert_header("Range", "bytes=16-31");
+        let res: tide::http::Response = app.respond(req).await.unwrap();
+
+        assert_eq!(res.status(), 200);
+        assert_eq!(res[headers::CONTENT_ENCODING], *encoding);
+        assert!(res.header(headers::ACCEPT_RANGES).is_none());
+    }
+}
+
+#[async_std::test]
+async fn accept_ranges_kept() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .strip_accept_ranges(false)
+            .build(),
+    );
+
+    for encoding in ENCODINGS {
+        let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+        req.insert_header(headers::ACCEPT_ENCODING, *encoding);
+        let res: tide::http::Response = app.respond(req).await.unwrap();
+
+        assert_eq!(res.status(), 200);
+        assert_eq!(res[headers::CONTENT_ENCODING], *encoding);
+        assert_eq!(res[headers::ACCEPT_RANGES], "bytes");
+    }
+}
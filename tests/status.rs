@@ -0,0 +1,88 @@
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+/// Responds with the status code from the url path, and always with a body.
+fn app_with(middleware: tide_compress::CompressMiddleware) -> tide::Server<()> {
+    let mut app = tide::new();
+    app.with(middleware);
+    app.at("/:status").get(|req: tide::Request<()>| async move {
+        let status: u16 = req.param("status")?.parse()?;
+        let mut res = Response::new(status);
+        res.set_body(TEXT.to_owned());
+        Ok(res)
+    });
+    app
+}
+
+async fn request(app: &tide::Server<()>, status: u16) -> tide::http::Response {
+    let url = Url::parse(&format!("http://_/{}", status)).unwrap();
+    let mut req = Request::new(Method::Get, url);
+    req.insert_header(headers::ACCEPT_ENCODING, "gzip");
+    app.respond(req).await.unwrap()
+}
+
+#[async_std::test]
+async fn bodiless_statuses_uncompressed() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+
+    for status in [204, 304] {
+        let res = request(&app, status).await;
+
+        assert_eq!(res.status(), status);
+        assert!(res.header(headers::CONTENT_ENCODING).is_none());
+        assert_eq!(res[headers::VARY], "accept-encoding");
+    }
+}
+
+#[async_std::test]
+async fn error_statuses_compressed_by_default() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+
+    for status in [200, 404, 500] {
+        let res = request(&app, status).await;
+
+        assert_eq!(res.status(), status);
+        assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+    }
+}
+
+#[async_std::test]
+async fn error_statuses_uncompressed() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .compress_errors(false)
+            .build(),
+    );
+
+    for status in [404, 500] {
+        let mut res = request(&app, status).await;
+
+        assert_eq!(res.status(), status);
+        assert!(res.header(headers::CONTENT_ENCODING).is_none());
+        assert_eq!(res.body_string().await.unwrap(), TEXT);
+    }
+
+    let res = request(&app, 200).await;
+    assert_eq!(res.status(), StatusCode::Ok);
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+}
@@ -0,0 +1,151 @@
+#![cfg(any(feature = "brotli", feature = "gzip"))]
+
+use std::time::Duration;
+
+#[cfg(feature = "brotli")]
+use async_compression::futures::bufread::BrotliDecoder;
+#[cfg(feature = "gzip")]
+use async_compression::futures::bufread::GzipDecoder;
+use async_std::channel::{self, Sender};
+use async_std::future::timeout;
+use futures_lite::io::{AsyncRead, AsyncReadExt, BufReader};
+use tide::http::{headers, Method, Request, Url};
+use tide_compress::FlushMode;
+
+/// Sends an event, then waits for the go-ahead before sending another.
+fn sse_app_with(middleware: tide_compress::CompressMiddleware) -> (tide::Server<()>, Sender<()>) {
+    let (go, wait) = channel::bounded::<()>(1);
+
+    let mut app = tide::new();
+    app.with(middleware);
+    app.at("/").get(tide::sse::endpoint(move |_req, sender| {
+        let wait = wait.clone();
+        async move {
+            sender.send("message", "one", None).await?;
+            wait.recv().await?;
+            sender.send("message", "two", None).await?;
+            Ok(())
+        }
+    }));
+    (app, go)
+}
+
+async fn request(app: &tide::Server<()>, accept_encoding: &str) -> tide::http::Response {
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, accept_encoding);
+    app.respond(req).await.unwrap()
+}
+
+/// Reads the decompressed body until it contains `expected`.
+async fn read_until(decoder: &mut (impl AsyncRead + Unpin), received: &mut String, expected: &str) {
+    let mut buf = [0; 1024];
+    while !received.contains(expected) {
+        let len = decoder.read(&mut buf).await.unwrap();
+        assert_ne!(len, 0, "body ended before {:?}", expected);
+        received.push_str(std::str::from_utf8(&buf[..len]).unwrap());
+    }
+}
+
+/// Reads the rest of the decompressed body.
+async fn read_to_end(decoder: &mut (impl AsyncRead + Unpin), received: &mut String) {
+    let mut rest = String::new();
+    decoder.read_to_string(&mut rest).await.unwrap();
+    received.push_str(&rest);
+}
+
+#[cfg(feature = "gzip")]
+#[async_std::test]
+async fn sse_flushed_immediately() {
+    let (app, go) = sse_app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+
+    let mut res = request(&app, "gzip").await;
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+    assert_eq!(res[headers::CONTENT_TYPE], "text/event-stream");
+
+    let mut decoder = GzipDecoder::new(BufReader::new(res.take_body()));
+    let mut received = String::new();
+
+    // The first event arrives while the stream is still open.
+    timeout(
+        Duration::from_secs(5),
+        read_until(&mut decoder, &mut received, "data:one\n\n"),
+    )
+    .await
+    .unwrap();
+
+    go.send(()).await.unwrap();
+    timeout(
+        Duration::from_secs(5),
+        read_until(&mut decoder, &mut received, "data:two\n\n"),
+    )
+    .await
+    .unwrap();
+
+    read_to_end(&mut decoder, &mut received).await;
+    assert_eq!(
+        received,
+        "event:message\ndata:one\n\nevent:message\ndata:two\n\n"
+    );
+}
+
+#[cfg(feature = "brotli")]
+#[async_std::test]
+async fn sse_flushed_when_idle() {
+    let (app, go) = sse_app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .streaming_flush(FlushMode::Idle(Duration::from_millis(10)))
+            .build(),
+    );
+
+    let mut res = request(&app, "br").await;
+    assert_eq!(res[headers::CONTENT_ENCODING], "br");
+
+    let mut decoder = BrotliDecoder::new(BufReader::new(res.take_body()));
+    let mut received = String::new();
+
+    timeout(
+        Duration::from_secs(5),
+        read_until(&mut decoder, &mut received, "data:one\n\n"),
+    )
+    .await
+    .unwrap();
+
+    go.send(()).await.unwrap();
+    read_to_end(&mut decoder, &mut received).await;
+    assert!(received.ends_with("data:two\n\n"));
+}
+
+#[cfg(feature = "gzip")]
+#[async_std::test]
+async fn sse_never_flushed() {
+    let (app, go) = sse_app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .streaming_flush(FlushMode::Never)
+            .build(),
+    );
+
+    let mut res = request(&app, "gzip").await;
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+
+    let mut decoder = GzipDecoder::new(BufReader::new(res.take_body()));
+    let mut received = String::new();
+
+    // The compressor holds on to the first event until the stream ends.
+    assert!(timeout(
+        Duration::from_millis(100),
+        read_until(&mut decoder, &mut received, "data:one\n\n"),
+    )
+    .await
+    .is_err());
+
+    go.send(()).await.unwrap();
+    read_to_end(&mut decoder, &mut received).await;
+    assert!(received.contains("data:one\n\n"));
+    assert!(received.ends_with("data:two\n\n"));
+}
@@ -32,7 +32,8 @@ async fn no_accepts_encoding() {
     assert_eq!(res.status(), 200);
     assert!(res.header(headers::CONTENT_LENGTH).is_none());
     assert!(res.header(headers::CONTENT_ENCODING).is_none());
-    assert!(res.header(headers::VARY).is_none());
+    // Uncompressed because of the request, so a shared cache must not serve it to clients accepting an encoding.
+    assert_eq!(res[headers::VARY], "accept-encoding");
     assert_eq!(res.body_string().await.unwrap(), TEXT);
 }
 
@@ -52,6 +53,16 @@ async fn invalid_accepts_encoding() {
 
     let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
     req.insert_header(headers::ACCEPT_ENCODING, "not_an_encoding");
+    let mut res: tide::http::Response = app.respond(req).await.unwrap();
+
+    // Identity is acceptable unless excluded, as per RFC 9110.
+    assert_eq!(res.status(), 200);
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(res[headers::VARY], "accept-encoding");
+    assert_eq!(res.body_string().await.unwrap(), TEXT);
+
+    let mut req = Request::new(Method::Get, Url::parse("http://_/").unwrap());
+    req.insert_header(headers::ACCEPT_ENCODING, "not_an_encoding, identity;q=0");
     let res: tide::http::Response = app.respond(req).await.unwrap();
 
     assert_eq!(res.status(), StatusCode::NotAcceptable);
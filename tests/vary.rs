@@ -0,0 +1,135 @@
+use tide::http::{headers, Method, Request, StatusCode, Url};
+use tide::Response;
+
+const TEXT: &str = concat![
+    "Chunk one\n",
+    "data data\n",
+    "\n",
+    "Chunk two\n",
+    "data data\n",
+    "\n",
+    "Chunk three\n",
+    "data data\n",
+];
+
+fn app_with(middleware: tide_compress::CompressMiddleware) -> tide::Server<()> {
+    let mut app = tide::new();
+    app.with(middleware);
+    app.at("/origin").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.insert_header(headers::VARY, "Origin, Accept-Language");
+        Ok(res)
+    });
+    app.at("/duplicate").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.append_header(headers::VARY, "Origin, Accept-Encoding");
+        res.append_header(headers::VARY, "origin");
+        Ok(res)
+    });
+    app.at("/wildcard").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.insert_header(headers::VARY, "*");
+        Ok(res)
+    });
+    app.at("/short").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body("short");
+        res.insert_header(headers::VARY, "Origin");
+        Ok(res)
+    });
+    app.at("/image").get(|_| async {
+        let mut res = Response::new(StatusCode::Ok);
+        res.set_body(TEXT.to_owned());
+        res.set_content_type("image/png");
+        Ok(res)
+    });
+    app
+}
+
+async fn request(
+    app: &tide::Server<()>,
+    path: &str,
+    accept_encoding: &str,
+) -> tide::http::Response {
+    let mut req = Request::new(
+        Method::Get,
+        Url::parse("http://_/").unwrap().join(path).unwrap(),
+    );
+    req.insert_header(headers::ACCEPT_ENCODING, accept_encoding);
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+    assert_eq!(res.status(), 200);
+    res
+}
+
+#[async_std::test]
+async fn merged_with_existing() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .build(),
+    );
+
+    let res = request(&app, "/origin", "gzip").await;
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+    assert_eq!(
+        res[headers::VARY],
+        "origin, accept-language, accept-encoding"
+    );
+
+    let res = request(&app, "/duplicate", "gzip").await;
+    assert_eq!(res[headers::VARY], "origin, accept-encoding");
+
+    let res = request(&app, "/wildcard", "gzip").await;
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+    assert_eq!(res[headers::VARY], "*");
+
+    // Skipped responses are merged too, by default.
+    let res = request(&app, "/short", "gzip").await;
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(res[headers::VARY], "origin, accept-encoding");
+}
+
+#[async_std::test]
+async fn not_added_when_skipped() {
+    let app = app_with(
+        tide_compress::CompressMiddleware::builder()
+            .threshold(16)
+            .vary_on_skip(false)
+            .build(),
+    );
+
+    // The response is the same whatever the request, so the existing `Vary` is left alone.
+    let res = request(&app, "/short", "gzip").await;
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(res[headers::VARY], "Origin");
+
+    let res = request(&app, "/image", "gzip").await;
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert!(res.header(headers::VARY).is_none());
+
+    // Compressed, or left uncompressed because of the request.
+    let res = request(&app, "/origin", "gzip").await;
+    assert_eq!(res[headers::CONTENT_ENCODING], "gzip");
+    assert_eq!(
+        res[headers::VARY],
+        "origin, accept-language, accept-encoding"
+    );
+
+    let res = request(&app, "/origin", "identity").await;
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(
+        res[headers::VARY],
+        "origin, accept-language, accept-encoding"
+    );
+
+    let req = Request::new(Method::Get, Url::parse("http://_/origin").unwrap());
+    let res: tide::http::Response = app.respond(req).await.unwrap();
+    assert!(res.header(headers::CONTENT_ENCODING).is_none());
+    assert_eq!(
+        res[headers::VARY],
+        "origin, accept-language, accept-encoding"
+    );
+}